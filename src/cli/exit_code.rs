@@ -0,0 +1,115 @@
+//! Stable exit codes for the `ling` CLI. Scripts driving `ling` need to
+//! distinguish "the daemon isn't running" from "the network is unreachable"
+//! from "a plain bug" without parsing message text — so every top-level
+//! command failure is classified into one of these before the process exits.
+//!
+//! Kept intentionally small and coarse: add a variant only when a script
+//! needs to branch on it, not for every distinct error message.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+    /// Command completed successfully.
+    Ok = 0,
+    /// Unclassified failure — the catch-all.
+    Internal = 1,
+    /// Bad CLI arguments. Matches clap's own exit code for parse errors, so
+    /// this code means "usage error" everywhere, not just here.
+    Usage = 2,
+    /// The command needed the background daemon and couldn't reach it.
+    NotRunning = 3,
+    /// A network call failed or was skipped by offline mode.
+    Network = 4,
+    /// The named resource (skill, agent, model, ...) doesn't exist.
+    NotFound = 5,
+}
+
+impl ExitCode {
+    pub fn code(self) -> i32 {
+        self as i32
+    }
+}
+
+impl fmt::Display for ExitCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ExitCode::Ok => "ok",
+            ExitCode::Internal => "internal",
+            ExitCode::Usage => "usage",
+            ExitCode::NotRunning => "not_running",
+            ExitCode::Network => "network",
+            ExitCode::NotFound => "not_found",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Text shown in `ling --help`'s `after_help`, documenting the taxonomy.
+pub const HELP_TEXT: &str = "Exit codes:\n  \
+    0  ok\n  \
+    1  internal    unclassified failure\n  \
+    2  usage       bad CLI arguments\n  \
+    3  not_running the background daemon isn't reachable\n  \
+    4  network     a network call failed, or offline mode skipped it\n  \
+    5  not_found   the named resource doesn't exist";
+
+/// Best-effort classification of a command's final error, from its message
+/// text and (where available) the underlying `reqwest`/`io` error kind.
+/// Commands don't thread typed errors through `anyhow` today, so this reads
+/// the handful of messages that already exist rather than downcasting to a
+/// dedicated error enum.
+pub fn classify(err: &anyhow::Error) -> ExitCode {
+    for cause in err.chain() {
+        if let Some(req_err) = cause.downcast_ref::<reqwest::Error>() {
+            // Only a failed connect to the local daemon means "not running";
+            // a failed connect to any other host is a network failure.
+            let local = req_err
+                .url()
+                .and_then(|u| u.host_str())
+                .is_some_and(|h| matches!(h, "127.0.0.1" | "localhost" | "[::1]"));
+            return if req_err.is_connect() && local {
+                ExitCode::NotRunning
+            } else {
+                ExitCode::Network
+            };
+        }
+        if let Some(io_err) = cause.downcast_ref::<std::io::Error>() {
+            if io_err.kind() == std::io::ErrorKind::ConnectionRefused {
+                return ExitCode::NotRunning;
+            }
+        }
+    }
+
+    let msg = err.to_string();
+    if msg.contains("offline mode") {
+        return ExitCode::Network;
+    }
+    if msg.contains("not found") || msg.contains("Not found") || msg.contains("No matching") {
+        return ExitCode::NotFound;
+    }
+    ExitCode::Internal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_offline_error_as_network() {
+        let err = crate::offline::offline_error("skill install");
+        assert_eq!(classify(&err), ExitCode::Network);
+    }
+
+    #[test]
+    fn classifies_not_found_message() {
+        let err = anyhow::anyhow!("Skill 'foo' not found at /tmp/foo");
+        assert_eq!(classify(&err), ExitCode::NotFound);
+    }
+
+    #[test]
+    fn defaults_to_internal() {
+        let err = anyhow::anyhow!("something went sideways");
+        assert_eq!(classify(&err), ExitCode::Internal);
+    }
+}
@@ -194,8 +194,29 @@ fn run_skill_install_scripts() {
     }
 }
 
+/// Lint each freshly-extracted skill and remove any that fail validation —
+/// a bulk `init` shouldn't let one malformed `SKILL.md` in the community
+/// repo silently break the assistant. Returns the names removed.
+fn reject_invalid_skills(target_dir: &PathBuf, installed: &[String]) -> Vec<String> {
+    let mut rejected = Vec::new();
+    for name in installed {
+        let skill_dir = target_dir.join(name);
+        let report = crate::extensions::skills::lint::lint_skill_dir(&skill_dir);
+        if !report.is_ok() {
+            let _ = fs::remove_dir_all(&skill_dir);
+            rejected.push(name.clone());
+        }
+    }
+    rejected
+}
+
 /// Download skills from the linggen/skills GitHub repo (best-effort).
 async fn install_default_skills() {
+    if crate::offline::is_offline() {
+        println!("  {}[SKIP]{} Skills download (offline mode)", YELLOW, RESET);
+        return;
+    }
+
     let target_dir = crate::paths::global_skills_dir();
 
     let (owner, repo) = ("linggen", "skills");
@@ -218,10 +239,18 @@ async fn install_default_skills() {
                 .context("Failed to extract skills")
             {
                 Ok(installed) if !installed.is_empty() => {
+                    let rejected = reject_invalid_skills(&target_dir, &installed);
+                    let ok_count = installed.len() - rejected.len();
                     println!(
                         "  {}[OK]{} Installed {} skills from linggen/skills",
-                        GREEN, RESET, installed.len()
+                        GREEN, RESET, ok_count
                     );
+                    if !rejected.is_empty() {
+                        println!(
+                            "  {}[WARN]{} Rejected {} invalid skill(s): {}",
+                            YELLOW, RESET, rejected.len(), rejected.join(", ")
+                        );
+                    }
                 }
                 Ok(_) => {
                     println!("  {}[SKIP]{} No skills found in repository", YELLOW, RESET);
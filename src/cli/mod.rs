@@ -1,5 +1,6 @@
 pub mod account;
 pub mod daemon;
+pub mod exit_code;
 pub mod init;
 pub mod self_update;
 pub mod skills_cmd;
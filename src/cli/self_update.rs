@@ -1,8 +1,97 @@
 use anyhow::{Context, Result};
-use serde::Deserialize;
-use std::path::Path;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+/// Release track a manifest is fetched from. Persisted so `ling update` and
+/// `ling status` (which polls the same manifest to report "latest") agree
+/// on which track the user opted into without re-passing `--channel` every time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReleaseChannel {
+    Stable,
+    Beta,
+    Nightly,
+}
+
+impl Default for ReleaseChannel {
+    fn default() -> Self {
+        Self::Stable
+    }
+}
+
+impl std::str::FromStr for ReleaseChannel {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "stable" => Ok(Self::Stable),
+            "beta" => Ok(Self::Beta),
+            "nightly" => Ok(Self::Nightly),
+            other => anyhow::bail!("unknown update channel '{other}' (expected stable, beta, or nightly)"),
+        }
+    }
+}
+
+impl std::fmt::Display for ReleaseChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Stable => "stable",
+            Self::Beta => "beta",
+            Self::Nightly => "nightly",
+        })
+    }
+}
+
+impl ReleaseChannel {
+    /// Manifest URL for this channel. Stable stays on the "latest release"
+    /// alias; beta/nightly are separate tagged releases the CI publishes
+    /// to on their own cadence.
+    pub fn manifest_url(self) -> String {
+        match self {
+            Self::Stable => {
+                "https://github.com/linggen/linggen/releases/latest/download/manifest.json"
+                    .to_string()
+            }
+            Self::Beta => {
+                "https://github.com/linggen/linggen/releases/download/beta/manifest.json"
+                    .to_string()
+            }
+            Self::Nightly => {
+                "https://github.com/linggen/linggen/releases/download/nightly/manifest.json"
+                    .to_string()
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct ChannelPref {
+    channel: ReleaseChannel,
+}
+
+/// `~/.linggen/update-channel.json` — the persisted channel preference.
+fn channel_file() -> PathBuf {
+    crate::paths::linggen_home().join("update-channel.json")
+}
+
+/// The persisted channel preference, or `stable` if none was ever set.
+pub fn load_channel() -> ReleaseChannel {
+    std::fs::read_to_string(channel_file())
+        .ok()
+        .and_then(|s| serde_json::from_str::<ChannelPref>(&s).ok())
+        .map(|p| p.channel)
+        .unwrap_or_default()
+}
+
+fn save_channel(channel: ReleaseChannel) -> Result<()> {
+    let file = channel_file();
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&file, serde_json::to_string_pretty(&ChannelPref { channel })?)?;
+    Ok(())
+}
+
 #[derive(Deserialize)]
 struct ReleaseManifest {
     version: String,
@@ -35,8 +124,22 @@ fn platform_slug() -> &'static str {
     }
 }
 
-/// Install/update the ling binary.
-pub async fn run() -> Result<()> {
+/// Install/update the ling binary. `channel` overrides (and persists) the
+/// release track; `None` reuses the last-selected channel (`stable` if
+/// never set).
+pub async fn run(channel: Option<ReleaseChannel>) -> Result<()> {
+    if crate::offline::is_offline() {
+        return Err(crate::offline::offline_error("self-update"));
+    }
+
+    let channel = match channel {
+        Some(c) => {
+            save_channel(c).context("Failed to save update channel preference")?;
+            c
+        }
+        None => load_channel(),
+    };
+
     let current_version = env!("CARGO_PKG_VERSION");
 
     let client = reqwest::Client::builder()
@@ -52,15 +155,8 @@ pub async fn run() -> Result<()> {
         .build()
         .context("Failed to build HTTP client")?;
 
-    println!("Current ling version: v{}", current_version);
-    update_binary(
-        &client,
-        "ling",
-        "https://github.com/linggen/linggen/releases/latest/download/manifest.json",
-        Some(current_version),
-        None,
-    )
-    .await?;
+    println!("Current ling version: v{} ({} channel)", current_version, channel);
+    update_binary(&client, "ling", &channel.manifest_url(), Some(current_version), None).await?;
 
     Ok(())
 }
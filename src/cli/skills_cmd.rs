@@ -1,7 +1,8 @@
 use crate::config::Config;
-use crate::extensions::skills::marketplace::{self, SkillScope};
+use crate::extensions::frontmatter;
+use crate::extensions::skills::marketplace::{self, LockedSkill, SkillScope, SkillsLock};
 use anyhow::Result;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Notify the running server to reload skills (best-effort, no error if server isn't running).
 async fn notify_reload(config: &Config) {
@@ -30,9 +31,116 @@ pub enum SkillsAction {
         global: bool,
     },
     List,
+    Update {
+        name: Option<String>,
+        all: bool,
+    },
+    /// Install exactly what `linggen-skills.lock` says (project scope only).
+    Sync {
+        /// Fail instead of updating the lockfile when an installed skill's
+        /// content hash no longer matches what's recorded — CI's "did anyone
+        /// forget to commit a refreshed lockfile" check.
+        frozen: bool,
+    },
     Search {
         query: String,
     },
+    /// Validate a skill directory without installing it.
+    Lint {
+        dir: PathBuf,
+    },
+}
+
+/// One installed skill found by `scan_installed_skills`: under
+/// `~/.linggen/skills/` or `{workspace}/.linggen/skills/` (the two dirs
+/// `add`/`remove` manage), or under the `.claude`/`.codex` compat dirs,
+/// which are read-only mirrors not installed through this CLI and carry no
+/// install origin, so `update` skips them.
+struct InstalledSkill {
+    name: String,
+    dir: PathBuf,
+    source: &'static str,
+}
+
+fn scan_installed_skills() -> Vec<InstalledSkill> {
+    let mut dirs_to_scan: Vec<(PathBuf, &str)> = [
+        Some((crate::paths::global_skills_dir(), "global")),
+        crate::paths::resolve_workspace_root(None)
+            .ok()
+            .map(|ws| (ws.join(".linggen/skills"), "project")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    // `.claude/skills` and `.codex/skills` — included for `list` since
+    // that's just visibility, but `update` naturally skips them: they
+    // don't carry `marketplace::load_origin` metadata (never installed via
+    // `skills add`), so the existing "no install origin recorded" branch
+    // below already treats them as not-ours-to-update. `remove` doesn't go
+    // through this scan at all.
+    dirs_to_scan.extend(crate::paths::compat_skills_dirs());
+
+    let mut found = Vec::new();
+    for (dir, source) in dirs_to_scan {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("?")
+                .to_string();
+
+            let is_skill = if path.is_dir() {
+                path.join("SKILL.md").exists()
+            } else {
+                path.extension().map_or(false, |e| e == "md")
+            };
+
+            if is_skill {
+                found.push(InstalledSkill {
+                    name,
+                    dir: path,
+                    source,
+                });
+            }
+        }
+    }
+    found
+}
+
+/// Read the `version` frontmatter field from a skill's definition file
+/// (`SKILL.md` inside a dir, or the `.md` file itself for flat skills).
+fn skill_version(skill_dir: &Path) -> Option<String> {
+    let def_path = if skill_dir.is_dir() {
+        skill_dir.join("SKILL.md")
+    } else {
+        skill_dir.to_path_buf()
+    };
+    let content = std::fs::read_to_string(def_path).ok()?;
+    frontmatter::parse_version(&content)
+}
+
+/// Record `name`'s freshly-installed origin + content hash in the project
+/// lockfile. Best-effort in the sense that a hashing failure shouldn't undo
+/// an otherwise-successful install, but is still surfaced as an error since
+/// silently skipping it would leave the lockfile quietly out of date.
+fn lock_installed_skill(project_root: &Path, name: &str, target_dir: &Path) -> Result<()> {
+    let Some(origin) = marketplace::load_origin(target_dir) else {
+        return Ok(());
+    };
+    let content_hash = marketplace::hash_skill_dir(target_dir)?;
+    let mut lock = SkillsLock::load(project_root)?.unwrap_or_default();
+    lock.upsert(LockedSkill {
+        name: name.to_string(),
+        repo: origin.repo,
+        git_ref: origin.git_ref,
+        source_registry: origin.source_registry,
+        content_hash,
+    });
+    lock.save(project_root)
 }
 
 pub async fn run(action: SkillsAction, config: &Config) -> Result<()> {
@@ -68,6 +176,10 @@ pub async fn run(action: SkillsAction, config: &Config) -> Result<()> {
             )
             .await?;
             println!("{}", msg);
+
+            if let Some(root) = &project_root {
+                lock_installed_skill(root, &name, &target_dir)?;
+            }
             notify_reload(config).await;
         }
         SkillsAction::Remove { name, global } => {
@@ -90,50 +202,171 @@ pub async fn run(action: SkillsAction, config: &Config) -> Result<()> {
         }
         SkillsAction::List => {
             println!("Installed skills:\n");
-            let mut found = false;
-
-            let dirs_to_scan: Vec<(PathBuf, &str)> = [
-                Some((crate::paths::global_skills_dir(), "global")),
-                crate::paths::resolve_workspace_root(None)
-                    .ok()
-                    .map(|ws| (ws.join(".linggen/skills"), "project")),
-            ]
-            .into_iter()
-            .flatten()
-            .collect();
-
-            for (dir, source) in dirs_to_scan {
-                if !dir.exists() {
+            let installed = scan_installed_skills();
+
+            if installed.is_empty() {
+                println!("  (none)");
+            }
+            for skill in &installed {
+                let version = skill_version(&skill.dir).unwrap_or_else(|| "-".to_string());
+                let origin = marketplace::load_origin(&skill.dir)
+                    .and_then(|o| o.repo.or(o.source_registry))
+                    .unwrap_or_else(|| "-".to_string());
+                println!(
+                    "  {:30} ({:7}) version={:10} origin={}",
+                    skill.name, skill.source, version, origin
+                );
+            }
+        }
+        SkillsAction::Update { name, all } => {
+            if name.is_none() && !all {
+                anyhow::bail!("Specify a skill name or pass --all");
+            }
+            if crate::offline::is_offline() {
+                return Err(crate::offline::offline_error("skill update"));
+            }
+            let installed = scan_installed_skills();
+            let targets: Vec<&InstalledSkill> = installed
+                .iter()
+                .filter(|s| name.as_deref().is_none_or(|n| n == s.name))
+                .collect();
+
+            if targets.is_empty() {
+                println!("No matching installed skill found.");
+                return Ok(());
+            }
+
+            for skill in targets {
+                let Some(origin) = marketplace::load_origin(&skill.dir) else {
+                    println!(
+                        "  {:30} skipped — no install origin recorded (installed manually or before `skills update` existed)",
+                        skill.name
+                    );
                     continue;
-                }
-                let entries = match std::fs::read_dir(&dir) {
-                    Ok(e) => e,
-                    Err(_) => continue,
                 };
-                for entry in entries.filter_map(|e| e.ok()) {
-                    let path = entry.path();
-                    let name = path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("?");
-
-                    // Check if it's a skill directory (has SKILL.md) or a .md file
-                    let is_skill = if path.is_dir() {
-                        path.join("SKILL.md").exists()
+                let result = if origin.source_registry.as_deref() == Some("clawhub") {
+                    marketplace::install_from_clawhub(
+                        &skill.name,
+                        origin.git_ref.as_deref(),
+                        &skill.dir,
+                        true,
+                    )
+                    .await
+                } else {
+                    marketplace::install_skill(
+                        &skill.name,
+                        origin.repo.as_deref(),
+                        origin.git_ref.as_deref(),
+                        &skill.dir,
+                        true,
+                        origin.source_registry.as_deref(),
+                    )
+                    .await
+                };
+                match result {
+                    Ok(msg) => println!("  {}", msg),
+                    Err(e) => println!("  {:30} update failed: {e:#}", skill.name),
+                }
+            }
+            notify_reload(config).await;
+        }
+        SkillsAction::Sync { frozen } => {
+            if crate::offline::is_offline() {
+                return Err(crate::offline::offline_error("skill sync"));
+            }
+            let project_root = crate::paths::resolve_workspace_root(None)?;
+            let Some(mut lock) = SkillsLock::load(&project_root)? else {
+                anyhow::bail!(
+                    "No {} found — run `ling skills add` first.",
+                    marketplace::lockfile_path(&project_root).display()
+                );
+            };
+
+            let mut stale = Vec::new();
+            for entry in lock.skills.clone() {
+                let target_dir =
+                    marketplace::skill_target_dir(&entry.name, SkillScope::Project, Some(&project_root))?;
+
+                if frozen {
+                    // `--frozen` exists to detect upstream drift without
+                    // touching disk — install into a staging dir first and
+                    // only materialize into `target_dir` once the hash is
+                    // confirmed to match, so a mismatch never leaves the
+                    // real skill dir and the lockfile inconsistent.
+                    let staging = tempfile::tempdir()
+                        .map_err(|e| anyhow::anyhow!("failed to create staging dir: {e}"))?;
+                    let staging_dir = staging.path().join(&entry.name);
+
+                    let result = if entry.source_registry.as_deref() == Some("clawhub") {
+                        marketplace::install_from_clawhub(
+                            &entry.name,
+                            entry.git_ref.as_deref(),
+                            &staging_dir,
+                            true,
+                        )
+                        .await
                     } else {
-                        path.extension().map_or(false, |e| e == "md")
+                        marketplace::install_skill(
+                            &entry.name,
+                            entry.repo.as_deref(),
+                            entry.git_ref.as_deref(),
+                            &staging_dir,
+                            true,
+                            entry.source_registry.as_deref(),
+                        )
+                        .await
                     };
+                    let msg = result?;
 
-                    if is_skill {
-                        println!("  {:30} ({})", name, source);
-                        found = true;
+                    let content_hash = marketplace::hash_skill_dir(&staging_dir)?;
+                    if content_hash != entry.content_hash {
+                        stale.push(entry.name.clone());
+                        continue;
+                    }
+
+                    if target_dir.exists() {
+                        std::fs::remove_dir_all(&target_dir)?;
+                    }
+                    marketplace::copy_dir_all(&staging_dir, &target_dir)?;
+                    println!("  {}", msg);
+                } else {
+                    let result = if entry.source_registry.as_deref() == Some("clawhub") {
+                        marketplace::install_from_clawhub(&entry.name, entry.git_ref.as_deref(), &target_dir, true)
+                            .await
+                    } else {
+                        marketplace::install_skill(
+                            &entry.name,
+                            entry.repo.as_deref(),
+                            entry.git_ref.as_deref(),
+                            &target_dir,
+                            true,
+                            entry.source_registry.as_deref(),
+                        )
+                        .await
+                    };
+                    let msg = result?;
+                    println!("  {}", msg);
+
+                    let content_hash = marketplace::hash_skill_dir(&target_dir)?;
+                    if content_hash != entry.content_hash {
+                        lock.upsert(LockedSkill {
+                            content_hash,
+                            ..entry
+                        });
                     }
                 }
             }
 
-            if !found {
-                println!("  (none)");
+            if !stale.is_empty() {
+                anyhow::bail!(
+                    "Lockfile is stale for: {} (content changed upstream since the lock was written). \
+                    Re-run `ling skills sync` without --frozen to refresh it.",
+                    stale.join(", ")
+                );
             }
+
+            lock.save(&project_root)?;
+            notify_reload(config).await;
         }
         SkillsAction::Search { query } => {
             println!("Searching community skills for '{}' ...\n", query);
@@ -162,6 +395,20 @@ pub async fn run(action: SkillsAction, config: &Config) -> Result<()> {
             }
             println!("\n  {} result(s)", results.len());
         }
+        SkillsAction::Lint { dir } => {
+            let report = crate::extensions::skills::lint::lint_skill_dir(&dir);
+            for warning in &report.warnings {
+                println!("  [WARN] {}", warning);
+            }
+            for error in &report.errors {
+                println!("  [ERROR] {}", error);
+            }
+            if report.is_ok() {
+                println!("  OK — {}", dir.display());
+            } else {
+                anyhow::bail!("{} failed validation", dir.display());
+            }
+        }
     }
 
     Ok(())
@@ -26,21 +26,26 @@ pub async fn run(config: &Config, config_path: Option<&Path>) -> Result<()> {
 
     // 1. Version + update check
     let current = env!("CARGO_PKG_VERSION");
-    let latest = fetch_latest_version().await;
+    let channel = crate::cli::self_update::load_channel();
+    let latest = fetch_latest_version(channel).await;
     match &latest {
         Some(v) if v != current => {
             println!(
-                "  Version:     v{current}  {DIM}(latest: v{v} — run `ling update`){RESET}"
+                "  Version:     v{current}  {DIM}({channel} channel, latest: v{v} — run `ling update`){RESET}"
             );
         }
         Some(_) => {
-            println!("  Version:     v{current}  {DIM}(up to date){RESET}");
+            println!("  Version:     v{current}  {DIM}({channel} channel, up to date){RESET}");
         }
         None => {
-            println!("  Version:     v{current}");
+            println!("  Version:     v{current}  {DIM}({channel} channel){RESET}");
         }
     }
 
+    if config.offline {
+        println!("  Offline:     {CYAN}enabled{RESET} (outbound network calls are disabled)");
+    }
+
     // 2. Config
     match config_path {
         Some(p) => println!("  Config:      {}", p.display()),
@@ -206,7 +211,11 @@ async fn is_port_listening(port: u16) -> bool {
     .unwrap_or(false)
 }
 
-async fn fetch_latest_version() -> Option<String> {
+async fn fetch_latest_version(channel: crate::cli::self_update::ReleaseChannel) -> Option<String> {
+    if crate::offline::is_offline() {
+        return None;
+    }
+
     #[derive(serde::Deserialize)]
     struct Manifest {
         version: String,
@@ -218,11 +227,7 @@ async fn fetch_latest_version() -> Option<String> {
         .build()
         .ok()?;
 
-    let resp = client
-        .get("https://github.com/linggen/linggen/releases/latest/download/manifest.json")
-        .send()
-        .await
-        .ok()?;
+    let resp = client.get(channel.manifest_url()).send().await.ok()?;
 
     if !resp.status().is_success() {
         return None;
@@ -22,6 +22,69 @@ pub struct Config {
     /// Surfaced in Settings → General → Pet.
     #[serde(default)]
     pub pet: PetConfig,
+    /// Air-gapped mode. When true, every outbound call the engine makes on
+    /// its own initiative (web search/fetch, update checks, skill
+    /// marketplace lookups/installs) short-circuits with a clear error
+    /// instead of attempting the network. Locally present models and data
+    /// are unaffected. Latched process-wide at startup; see `offline.rs`.
+    #[serde(default)]
+    pub offline: bool,
+    /// `/mcp` invocation audit log settings. See `server::mcp::record_audit`.
+    #[serde(default)]
+    pub mcp: McpConfig,
+    /// Skill marketplace / Git-source settings. See [`SkillsConfig`].
+    #[serde(default)]
+    pub skills: SkillsConfig,
+}
+
+/// Settings for skill installation from Git-hosted repositories.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SkillsConfig {
+    /// Self-hosted GitLab/Bitbucket/GitHub Enterprise hostnames to trust in
+    /// addition to `github.com`, `gitlab.com`, and `bitbucket.org`, which are
+    /// always allowed. A skill `--repo` URL (or Git source elsewhere) on a
+    /// host not in this set is rejected rather than silently fetched.
+    #[serde(default)]
+    pub git_host_allowlist: Vec<String>,
+}
+
+/// Settings for the `/mcp` per-tool invocation audit log — visibility into
+/// what a third-party MCP caller (Cursor, Codex, Claude Code) asked for.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct McpConfig {
+    /// Master switch for the in-memory audit log.
+    #[serde(default = "default_true")]
+    pub audit_log: bool,
+    /// Redact each call's argument values in the log, keeping only their
+    /// key names — on by default since arguments can carry code snippets,
+    /// search queries, or file contents the caller is sending off-machine.
+    #[serde(default = "default_true")]
+    pub redact_args: bool,
+    /// Entries kept before the oldest is dropped. A ring buffer, not
+    /// persisted — restart clears it, matching `RunStore`'s posture.
+    #[serde(default = "default_mcp_audit_capacity")]
+    pub audit_capacity: usize,
+    /// Allow `memory_add`/`memory_update`/`memory_delete`/`memory_issue_resolve`
+    /// over MCP. Off, a third-party caller (Cursor, Codex) can still read the
+    /// knowledge base but can't write conclusions back into it. On by default
+    /// since that write path already shipped before this flag existed.
+    #[serde(default = "default_true")]
+    pub memory_write_enabled: bool,
+}
+
+impl Default for McpConfig {
+    fn default() -> Self {
+        Self {
+            audit_log: true,
+            redact_args: true,
+            audit_capacity: default_mcp_audit_capacity(),
+            memory_write_enabled: true,
+        }
+    }
+}
+
+fn default_mcp_audit_capacity() -> usize {
+    500
 }
 
 /// Settings for the desktop pet / companion (Yinyue today). The recall fields
@@ -200,6 +263,13 @@ pub struct AgentConfig {
     /// host. Trailing slash optional; no path segment.
     #[serde(default = "default_ling_mem_url")]
     pub ling_mem_url: String,
+
+    /// Opt-in: record every completed LLM call (prompt, response, latency,
+    /// token usage) to `~/.linggen/traces/llm.jsonl` for debugging bad
+    /// generations. Off by default — prompts/responses can contain
+    /// sensitive content. See `provider::trace`.
+    #[serde(default)]
+    pub llm_trace_enabled: bool,
 }
 
 
@@ -278,6 +348,10 @@ pub struct LoggingConfig {
     pub level: Option<String>,
     pub directory: Option<String>,
     pub retention_days: Option<u64>,
+    /// `"pretty"` (default) or `"json"`. Applies to both the stdout and file
+    /// layers — a sidecar's log aggregator usually wants json, a terminal
+    /// wants pretty.
+    pub format: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -523,11 +597,13 @@ impl Default for Config {
                 memory_inject_min_score: default_memory_inject_min_score(),
                 memory_recall_count: default_memory_recall_count(),
                 ling_mem_url: default_ling_mem_url(),
+                llm_trace_enabled: false,
             },
             logging: LoggingConfig {
                 level: None,
                 directory: None,
                 retention_days: None,
+                format: None,
             },
             agents: Vec::new(),
             routing: RoutingConfig {
@@ -540,6 +616,9 @@ impl Default for Config {
             },
             home_path: None,
             pet: PetConfig::default(),
+            offline: false,
+            mcp: McpConfig::default(),
+            skills: SkillsConfig::default(),
         }
     }
 }
@@ -97,6 +97,14 @@ pub struct Mission {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
 
+    /// Watchdog ceiling on a single run's wall-clock time (frontmatter
+    /// `max-runtime-secs`). A run that hangs past this is cancelled through
+    /// the same path as a user cancellation, so it is recorded `cancelled`
+    /// (not `failed`), instead of holding the mission's in-flight claim
+    /// forever and starving every later trigger. `None` = no ceiling.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_runtime_secs: Option<u64>,
+
     pub created_at: u64,
 }
 
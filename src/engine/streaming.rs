@@ -173,6 +173,7 @@ impl AgentEngine {
         messages: &[ChatMessage],
     ) -> Result<StreamResult> {
         use crate::provider::models::StreamChunk;
+        let trace_start = std::time::Instant::now();
         let mut stream = self
             .model_manager
             .chat_text_stream(model_id, messages, self.reasoning_effort.as_deref(), self.app_product())
@@ -229,6 +230,15 @@ impl AgentEngine {
         // Strip <think>...</think> blocks from the accumulated text.
         let accumulated = strip_think_tags(&accumulated);
 
+        crate::provider::trace::record_llm_trace(
+            model_id,
+            messages,
+            &accumulated,
+            trace_start.elapsed().as_millis(),
+            token_usage.as_ref(),
+            None,
+        );
+
         Ok(StreamResult {
             full_text: accumulated,
             token_usage,
@@ -250,6 +260,7 @@ impl AgentEngine {
     ) -> Result<StreamResult> {
         use crate::provider::models::StreamChunk;
 
+        let trace_start = std::time::Instant::now();
         let mut stream = self
             .model_manager
             .chat_tool_stream(model_id, messages, tools, self.reasoning_effort.as_deref(), self.app_product())
@@ -379,6 +390,15 @@ impl AgentEngine {
             );
         }
 
+        crate::provider::trace::record_llm_trace(
+            model_id,
+            messages,
+            &accumulated_text,
+            trace_start.elapsed().as_millis(),
+            token_usage.as_ref(),
+            None,
+        );
+
         Ok(StreamResult {
             full_text: accumulated_text,
             token_usage,
@@ -84,6 +84,7 @@ pub enum ToolResult {
         content: String,
         content_type: String,
         truncated: bool,
+        possible_prompt_injection: bool,
     },
     AskUserResponse {
         answers: Vec<AskUserAnswer>,
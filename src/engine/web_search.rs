@@ -22,6 +22,10 @@ pub struct WebSearchResult {
 /// token). There is no per-user Tavily key: the proxy holds the key and meters
 /// each search against the account's monthly pool, so sign-in is required.
 pub async fn web_search(query: &str, max_results: usize) -> Result<Vec<WebSearchResult>> {
+    if crate::offline::is_offline() {
+        return Err(crate::offline::offline_error("web search"));
+    }
+
     let (token, _) = crate::account::resolve_token()
         .context("Please sign in to linggen.dev to use web search.")?;
 
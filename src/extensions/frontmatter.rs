@@ -46,6 +46,22 @@ pub fn parse_meta(text: &str) -> Option<(String, String)> {
     Some((meta.name, meta.description))
 }
 
+/// Extract the optional `version` field from frontmatter, if declared —
+/// used by `linggen skills list` to show what's installed without a full
+/// parse. Most skills don't set this; `None` just means "unversioned".
+pub fn parse_version(text: &str) -> Option<String> {
+    let (yaml, _) = split(text);
+    let yaml = yaml?;
+
+    #[derive(Deserialize)]
+    struct VersionOnly {
+        #[serde(default)]
+        version: Option<String>,
+    }
+
+    serde_yml::from_str::<VersionOnly>(yaml).ok()?.version
+}
+
 /// Serde deserialiser for a list field that accepts either:
 ///   - a single string (`allowed-tools: "Bash, Read"` → split on `,`), or
 ///   - a true list (`allowed-tools: [Bash, Read]`).
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::{BTreeSet, HashSet};
 use std::fs;
 use std::path::{Component, Path, PathBuf};
@@ -36,6 +37,141 @@ pub struct MarketplaceSkill {
     pub source_registry: Option<String>,
 }
 
+/// Sidecar filename recording where an installed skill came from — read
+/// back by `linggen skills update` to re-fetch from the same place.
+const ORIGIN_FILE: &str = ".linggen-skill-origin.json";
+
+/// Install provenance for one skill, persisted alongside it. `None` fields
+/// mean "not applicable to this source" (e.g. a ClawHub install has no
+/// `repo`/`git_ref`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkillOrigin {
+    pub repo: Option<String>,
+    pub git_ref: Option<String>,
+    pub source_registry: Option<String>,
+}
+
+/// Persist `origin` as `{target_dir}/.linggen-skill-origin.json`. Best-effort
+/// — a failure here shouldn't fail the install itself.
+fn save_origin(target_dir: &Path, origin: &SkillOrigin) {
+    let path = target_dir.join(ORIGIN_FILE);
+    match serde_json::to_string_pretty(origin) {
+        Ok(json) => {
+            if let Err(e) = fs::write(&path, json) {
+                tracing::warn!(path = %path.display(), err = %e, "Failed to write skill origin");
+            }
+        }
+        Err(e) => tracing::warn!(err = %e, "Failed to serialize skill origin"),
+    }
+}
+
+/// Load a previously-persisted origin, if any (manually-installed or
+/// pre-existing skills won't have one).
+pub fn load_origin(target_dir: &Path) -> Option<SkillOrigin> {
+    let content = fs::read_to_string(target_dir.join(ORIGIN_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Run `skills::lint` against a just-extracted skill and remove it if it
+/// fails — a malformed `SKILL.md` should never end up in the installed set,
+/// silently breaking the assistant the first time it's activated.
+fn reject_if_invalid(name: &str, target_dir: &Path) -> Result<()> {
+    let report = super::skills::lint::lint_skill_dir(target_dir);
+    for warning in &report.warnings {
+        tracing::warn!(skill = %name, %warning, "Skill lint warning");
+    }
+    if !report.is_ok() {
+        let _ = fs::remove_dir_all(target_dir);
+        anyhow::bail!(
+            "Skill '{}' failed validation and was not installed:\n  - {}",
+            name,
+            report.errors.join("\n  - ")
+        );
+    }
+    Ok(())
+}
+
+/// Filename for the project skills lockfile, committed alongside `linggen.toml`
+/// so a team installs identical skill versions. Modeled on `Cargo.lock`: lives
+/// at the project root, not under `.linggen/`.
+const LOCKFILE_NAME: &str = "linggen-skills.lock";
+
+/// One pinned skill entry in `linggen-skills.lock`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LockedSkill {
+    pub name: String,
+    pub repo: Option<String>,
+    pub git_ref: Option<String>,
+    pub source_registry: Option<String>,
+    pub content_hash: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkillsLock {
+    pub skills: Vec<LockedSkill>,
+}
+
+pub fn lockfile_path(project_root: &Path) -> PathBuf {
+    project_root.join(LOCKFILE_NAME)
+}
+
+impl SkillsLock {
+    pub fn load(project_root: &Path) -> Result<Option<Self>> {
+        let path = lockfile_path(project_root);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let lock = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(lock))
+    }
+
+    pub fn save(&self, project_root: &Path) -> Result<()> {
+        let path = lockfile_path(project_root);
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Insert or replace the entry for `name`, keeping the list sorted by name
+    /// so the lockfile diffs cleanly in git.
+    pub fn upsert(&mut self, entry: LockedSkill) {
+        self.skills.retain(|s| s.name != entry.name);
+        self.skills.push(entry);
+        self.skills.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+}
+
+/// Hash a skill directory's contents (relative path + bytes of every file,
+/// in sorted order) so the same skill re-downloaded from the same ref always
+/// hashes the same regardless of directory-walk order.
+pub fn hash_skill_dir(dir: &Path) -> Result<String> {
+    let mut files = Vec::new();
+    collect_files(dir, dir, &mut files)?;
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for rel_path in files {
+        hasher.update(rel_path.to_string_lossy().as_bytes());
+        hasher.update(fs::read(dir.join(&rel_path))?);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn collect_files(root: &Path, dir: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(root, &path, out)?;
+        } else {
+            out.push(path.strip_prefix(root)?.to_path_buf());
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum SkillScope {
@@ -132,6 +268,10 @@ pub fn http_client() -> Result<reqwest::Client> {
 /// Results are interleaved from both sources (each pre-sorted by relevance),
 /// so neither source dominates the top of the list.
 pub async fn search_community(query: &str) -> Result<Vec<MarketplaceSkill>> {
+    if crate::offline::is_offline() {
+        return Err(crate::offline::offline_error("skill marketplace search"));
+    }
+
     let (sh_result, ch_result) = tokio::join!(
         search_skills_sh_community(query),
         search_clawhub(query),
@@ -247,6 +387,9 @@ fn validate_slug(slug: &str) -> Result<()> {
 
 /// Fetch security scan info from ClawHub for a skill.
 pub async fn fetch_clawhub_scan(slug: &str) -> Result<ClawHubScanResult> {
+    if crate::offline::is_offline() {
+        return Err(crate::offline::offline_error("ClawHub scan lookup"));
+    }
     validate_slug(slug)?;
     let client = http_client()?;
     let encoded_slug = url::form_urlencoded::byte_serialize(slug.as_bytes()).collect::<String>();
@@ -271,6 +414,10 @@ pub async fn install_skill(
     force: bool,
     source_registry: Option<&str>,
 ) -> Result<String> {
+    if crate::offline::is_offline() {
+        return Err(crate::offline::offline_error("skill install"));
+    }
+
     // Route to ClawHub if source is clawhub
     if source_registry == Some("clawhub") {
         return install_from_clawhub(name, None, target_dir, force).await;
@@ -280,8 +427,8 @@ pub async fn install_skill(
     let repo_url = repo_url.unwrap_or(DEFAULT_SKILLS_REPO);
     let git_ref = git_ref.unwrap_or("main");
 
-    let normalized = normalize_github_url(repo_url)?;
-    let (owner, repo) = parse_github_url(&normalized)?;
+    let normalized = normalize_repo_url(repo_url)?;
+    let (host, owner, repo) = parse_repo_url(&normalized)?;
 
     // Check existing
     if target_dir.exists() {
@@ -297,7 +444,7 @@ pub async fn install_skill(
     }
 
     // Download ZIP
-    let zip_url = build_github_zip_url(&owner, &repo, git_ref);
+    let zip_url = build_archive_zip_url(&host, &owner, &repo, git_ref);
     let client = http_client()?;
     let temp_zip = download_to_temp(&client, &zip_url).await?;
 
@@ -307,10 +454,19 @@ pub async fn install_skill(
 
     match result {
         Ok(_) => {
+            reject_if_invalid(name, target_dir)?;
             // Run install script if declared in frontmatter.
             if let Err(e) = super::skills::run_install_script(target_dir) {
                 tracing::warn!(skill = %name, err = %e, "Install script failed");
             }
+            save_origin(
+                target_dir,
+                &SkillOrigin {
+                    repo: Some(repo_url.to_string()),
+                    git_ref: Some(git_ref.to_string()),
+                    source_registry: source_registry.map(str::to_string),
+                },
+            );
             Ok(format!(
                 "Skill '{}' installed to {}",
                 name,
@@ -352,9 +508,9 @@ async fn install_skill_inner(
     git_ref: &str,
     target_dir: &Path,
 ) -> Result<String> {
-    let normalized = normalize_github_url(repo_url)?;
-    let (owner, repo) = parse_github_url(&normalized)?;
-    let zip_url = build_github_zip_url(&owner, &repo, git_ref);
+    let normalized = normalize_repo_url(repo_url)?;
+    let (host, owner, repo) = parse_repo_url(&normalized)?;
+    let zip_url = build_archive_zip_url(&host, &owner, &repo, git_ref);
     let client = http_client()?;
     let temp_zip = download_to_temp(&client, &zip_url).await?;
 
@@ -362,10 +518,19 @@ async fn install_skill_inner(
     let _ = fs::remove_file(&temp_zip);
 
     result?;
+    reject_if_invalid(name, target_dir)?;
     // Run install script if declared in frontmatter.
     if let Err(e) = super::skills::run_install_script(target_dir) {
         tracing::warn!(skill = %name, err = %e, "Install script failed");
     }
+    save_origin(
+        target_dir,
+        &SkillOrigin {
+            repo: Some(repo_url.to_string()),
+            git_ref: Some(git_ref.to_string()),
+            source_registry: None,
+        },
+    );
     Ok(format!(
         "Skill '{}' installed to {}",
         name,
@@ -424,10 +589,19 @@ pub async fn install_from_clawhub(
     // Move contents from extracted dir to target_dir
     copy_dir_all(&extracted_dir, target_dir)?;
 
+    reject_if_invalid(slug, target_dir)?;
     // Run install script if declared in frontmatter.
     if let Err(e) = super::skills::run_install_script(target_dir) {
         tracing::warn!(skill = %slug, err = %e, "Install script failed");
     }
+    save_origin(
+        target_dir,
+        &SkillOrigin {
+            repo: None,
+            git_ref: version.map(str::to_string),
+            source_registry: Some("clawhub".to_string()),
+        },
+    );
     Ok(format!(
         "Skill '{}' installed from ClawHub to {}",
         slug,
@@ -514,7 +688,7 @@ pub fn move_skill_to_global(
     Ok(format!("Moved '{}' to {}", name, dest_dir.display()))
 }
 
-fn copy_dir_all(src: &Path, dst: &Path) -> Result<()> {
+pub(crate) fn copy_dir_all(src: &Path, dst: &Path) -> Result<()> {
     fs::create_dir_all(dst)?;
     for entry in fs::read_dir(src)? {
         let entry = entry?;
@@ -530,54 +704,119 @@ fn copy_dir_all(src: &Path, dst: &Path) -> Result<()> {
 }
 
 // ---------------------------------------------------------------------------
-// GitHub URL helpers
+// Git host URL helpers (GitHub, GitLab, Bitbucket, and self-hosted allowlist)
 // ---------------------------------------------------------------------------
 
-pub fn normalize_github_url(url: &str) -> Result<String> {
-    let url = url.trim().trim_end_matches(".git").trim_end_matches('/');
-
-    if url.starts_with("https://github.com/") {
-        Ok(url.to_string())
-    } else if url.starts_with("git@github.com:") {
-        let repo = url.trim_start_matches("git@github.com:");
-        Ok(format!("https://github.com/{}", repo))
-    } else if !url.contains("://") && url.contains('/') {
-        Ok(format!("https://github.com/{}", url))
-    } else if url.contains("github.com") {
-        Ok(url.to_string())
+/// Which archive-URL convention a host uses. Only GitHub, GitLab, and
+/// Bitbucket shapes are known; a self-hosted instance is classified by a
+/// `gitlab`/`bitbucket` substring in its hostname, falling back to the
+/// GitHub shape (also correct for GitHub Enterprise) otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GitHostKind {
+    GitHub,
+    GitLab,
+    Bitbucket,
+}
+
+fn classify_host(host: &str) -> GitHostKind {
+    let host = host.to_ascii_lowercase();
+    if host.contains("gitlab") {
+        GitHostKind::GitLab
+    } else if host.contains("bitbucket") {
+        GitHostKind::Bitbucket
     } else {
-        anyhow::bail!("Only GitHub repositories are supported: {}", url)
+        GitHostKind::GitHub
     }
 }
 
-pub fn parse_github_url(url: &str) -> Result<(String, String)> {
-    let stripped = url.trim_start_matches("https://github.com/");
-    let parts: Vec<&str> = stripped.split('/').collect();
-    if parts.len() >= 2 {
-        return Ok((parts[0].to_string(), parts[1].to_string()));
+/// Normalize a repo reference into a full `https://{host}/{owner}/{repo}`
+/// URL. Accepts a GitHub-style `owner/repo` shorthand (defaults to
+/// `github.com`), `https://`/`http://` URLs, and `git@host:owner/repo` SSH
+/// form. The host — shorthand aside — must be `github.com`, `gitlab.com`,
+/// `bitbucket.org`, or listed in `[skills] git_host_allowlist`.
+pub fn normalize_repo_url(url: &str) -> Result<String> {
+    let trimmed = url.trim().trim_end_matches(".git").trim_end_matches('/');
+
+    if let Some(rest) = trimmed.strip_prefix("git@") {
+        let Some((host, path)) = rest.split_once(':') else {
+            anyhow::bail!("Could not parse Git SSH URL: {}", url);
+        };
+        return require_trusted_host(host).map(|_| format!("https://{}/{}", host, path));
+    }
+
+    if let Some(rest) = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"))
+    {
+        let host = rest.split('/').next().unwrap_or("");
+        return require_trusted_host(host).map(|_| format!("https://{}", rest));
+    }
+
+    if !trimmed.contains("://") && trimmed.contains('/') {
+        return Ok(format!("https://github.com/{}", trimmed));
     }
-    anyhow::bail!("Could not parse GitHub repository from '{}'", url)
+
+    anyhow::bail!("Could not parse Git repository URL: {}", url)
 }
 
-pub(crate) fn build_github_zip_url(owner: &str, repo: &str, git_ref: &str) -> String {
-    if git_ref.starts_with("refs/") {
-        format!(
-            "https://github.com/{}/{}/archive/{}.zip",
-            owner, repo, git_ref
-        )
-    } else if git_ref.starts_with("heads/") || git_ref.starts_with("tags/") {
-        format!(
-            "https://github.com/{}/{}/archive/refs/{}.zip",
-            owner, repo, git_ref
-        )
+fn require_trusted_host(host: &str) -> Result<()> {
+    if crate::git_host::is_allowed(host) {
+        Ok(())
     } else {
-        format!(
-            "https://github.com/{}/{}/archive/refs/heads/{}.zip",
-            owner, repo, git_ref
+        anyhow::bail!(
+            "Git host '{}' is not trusted. Add it to `[skills] git_host_allowlist` in linggen.toml to allow it.",
+            host
         )
     }
 }
 
+/// Split a normalized `https://{host}/{owner}/{repo}[/...]` URL into its
+/// `(host, owner, repo)` parts.
+pub fn parse_repo_url(url: &str) -> Result<(String, String, String)> {
+    let without_scheme = url
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    let mut segments = without_scheme.split('/');
+    let (Some(host), Some(owner), Some(repo)) =
+        (segments.next(), segments.next(), segments.next())
+    else {
+        anyhow::bail!("Could not parse repository from '{}'", url);
+    };
+    if host.is_empty() || owner.is_empty() || repo.is_empty() {
+        anyhow::bail!("Could not parse repository from '{}'", url);
+    }
+    Ok((host.to_string(), owner.to_string(), repo.to_string()))
+}
+
+/// Build the ZIP archive download URL for `owner/repo` at `git_ref` on
+/// `host`, using whichever of GitHub/GitLab/Bitbucket's archive-URL
+/// conventions matches the host.
+pub(crate) fn build_archive_zip_url(host: &str, owner: &str, repo: &str, git_ref: &str) -> String {
+    match classify_host(host) {
+        GitHostKind::GitHub => {
+            let ref_path = if git_ref.starts_with("refs/") {
+                git_ref.to_string()
+            } else if git_ref.starts_with("heads/") || git_ref.starts_with("tags/") {
+                format!("refs/{}", git_ref)
+            } else {
+                format!("refs/heads/{}", git_ref)
+            };
+            format!("https://{}/{}/{}/archive/{}.zip", host, owner, repo, ref_path)
+        }
+        GitHostKind::GitLab => format!(
+            "https://{}/{}/{}/-/archive/{}/{}-{}.zip",
+            host, owner, repo, git_ref, repo, git_ref
+        ),
+        GitHostKind::Bitbucket => {
+            format!("https://{}/{}/{}/get/{}.zip", host, owner, repo, git_ref)
+        }
+    }
+}
+
+pub(crate) fn build_github_zip_url(owner: &str, repo: &str, git_ref: &str) -> String {
+    build_archive_zip_url("github.com", owner, repo, git_ref)
+}
+
 fn is_default_repo(normalized_url: &str) -> bool {
     normalized_url == DEFAULT_SKILLS_REPO
 }
@@ -937,73 +1176,104 @@ async fn search_skills_sh(query: &str) -> Result<Option<SkillsShSkill>> {
 mod tests {
     use super::*;
 
-    // ---- normalize_github_url ----
+    // ---- normalize_repo_url ----
 
     #[test]
     fn test_normalize_https_url() {
-        let result = normalize_github_url("https://github.com/linggen/skills").unwrap();
+        let result = normalize_repo_url("https://github.com/linggen/skills").unwrap();
         assert_eq!(result, "https://github.com/linggen/skills");
     }
 
     #[test]
     fn test_normalize_https_url_with_git_suffix() {
-        let result = normalize_github_url("https://github.com/linggen/skills.git").unwrap();
+        let result = normalize_repo_url("https://github.com/linggen/skills.git").unwrap();
         assert_eq!(result, "https://github.com/linggen/skills");
     }
 
     #[test]
     fn test_normalize_https_url_with_trailing_slash() {
-        let result = normalize_github_url("https://github.com/linggen/skills/").unwrap();
+        let result = normalize_repo_url("https://github.com/linggen/skills/").unwrap();
         assert_eq!(result, "https://github.com/linggen/skills");
     }
 
     #[test]
     fn test_normalize_shorthand() {
-        let result = normalize_github_url("linggen/skills").unwrap();
+        let result = normalize_repo_url("linggen/skills").unwrap();
         assert_eq!(result, "https://github.com/linggen/skills");
     }
 
     #[test]
     fn test_normalize_git_ssh() {
-        let result = normalize_github_url("git@github.com:linggen/skills").unwrap();
+        let result = normalize_repo_url("git@github.com:linggen/skills").unwrap();
         assert_eq!(result, "https://github.com/linggen/skills");
     }
 
     #[test]
     fn test_normalize_git_ssh_with_git_suffix() {
-        let result = normalize_github_url("git@github.com:linggen/skills.git").unwrap();
+        let result = normalize_repo_url("git@github.com:linggen/skills.git").unwrap();
         assert_eq!(result, "https://github.com/linggen/skills");
     }
 
     #[test]
-    fn test_normalize_unsupported_url() {
-        let err = normalize_github_url("https://gitlab.com/foo/bar").unwrap_err();
-        assert!(err.to_string().contains("Only GitHub"));
+    fn test_normalize_gitlab_url() {
+        let result = normalize_repo_url("https://gitlab.com/foo/bar").unwrap();
+        assert_eq!(result, "https://gitlab.com/foo/bar");
     }
 
-    // ---- parse_github_url ----
+    #[test]
+    fn test_normalize_bitbucket_ssh() {
+        let result = normalize_repo_url("git@bitbucket.org:foo/bar.git").unwrap();
+        assert_eq!(result, "https://bitbucket.org/foo/bar");
+    }
 
     #[test]
-    fn test_parse_github_url_basic() {
-        let (owner, repo) = parse_github_url("https://github.com/linggen/skills").unwrap();
+    fn test_normalize_untrusted_host_rejected() {
+        let err = normalize_repo_url("https://git.example.com/foo/bar").unwrap_err();
+        assert!(err.to_string().contains("not trusted"));
+    }
+
+    // ---- parse_repo_url ----
+
+    #[test]
+    fn test_parse_repo_url_basic() {
+        let (host, owner, repo) = parse_repo_url("https://github.com/linggen/skills").unwrap();
+        assert_eq!(host, "github.com");
         assert_eq!(owner, "linggen");
         assert_eq!(repo, "skills");
     }
 
     #[test]
-    fn test_parse_github_url_with_extra_path() {
-        let (owner, repo) =
-            parse_github_url("https://github.com/linggen/skills/tree/main/foo").unwrap();
+    fn test_parse_repo_url_with_extra_path() {
+        let (host, owner, repo) =
+            parse_repo_url("https://github.com/linggen/skills/tree/main/foo").unwrap();
+        assert_eq!(host, "github.com");
         assert_eq!(owner, "linggen");
         assert_eq!(repo, "skills");
     }
 
     #[test]
-    fn test_parse_github_url_invalid() {
-        let err = parse_github_url("https://github.com/onlyowner").unwrap_err();
+    fn test_parse_repo_url_invalid() {
+        let err = parse_repo_url("https://github.com/onlyowner").unwrap_err();
         assert!(err.to_string().contains("Could not parse"));
     }
 
+    // ---- build_archive_zip_url ----
+
+    #[test]
+    fn test_build_zip_url_gitlab() {
+        let url = build_archive_zip_url("gitlab.com", "foo", "bar", "main");
+        assert_eq!(
+            url,
+            "https://gitlab.com/foo/bar/-/archive/main/bar-main.zip"
+        );
+    }
+
+    #[test]
+    fn test_build_zip_url_bitbucket() {
+        let url = build_archive_zip_url("bitbucket.org", "foo", "bar", "main");
+        assert_eq!(url, "https://bitbucket.org/foo/bar/get/main.zip");
+    }
+
     // ---- build_github_zip_url ----
 
     #[test]
@@ -161,6 +161,7 @@ impl MissionLoader {
                 .clone()
                 .unwrap_or_else(|| MISSION_AGENT_ID.to_string()),
             project: draft.project.clone().flatten(),
+            max_runtime_secs: None,
             created_at: crate::util::now_ts_secs(),
         };
 
@@ -79,6 +79,14 @@ struct MissionFrontmatter {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     project: Option<String>,
+
+    #[serde(
+        rename = "max-runtime-secs",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    max_runtime_secs: Option<u64>,
+
     #[serde(default, skip_serializing_if = "is_zero")]
     created_at: u64,
 }
@@ -155,6 +163,7 @@ pub(super) fn parse_mission_md(id: &str, content: &str) -> Result<Mission> {
         prompt: body,
         agent_id: fm.agent.unwrap_or_else(|| MISSION_AGENT_ID.to_string()),
         project: fm.project,
+        max_runtime_secs: fm.max_runtime_secs,
         created_at: fm.created_at,
     })
 }
@@ -178,6 +187,7 @@ fn default_mission(id: String, prompt: String) -> Mission {
         prompt,
         agent_id: MISSION_AGENT_ID.to_string(),
         project: None,
+        max_runtime_secs: None,
         created_at: 0,
     }
 }
@@ -224,6 +234,7 @@ fn parse_legacy(id: &str, yaml: &str, body: String) -> Result<Mission> {
         prompt,
         agent_id: MISSION_AGENT_ID.to_string(),
         project: fm.project,
+        max_runtime_secs: None,
         created_at: fm.created_at,
     })
 }
@@ -254,6 +265,7 @@ pub(super) fn mission_to_md(mission: &Mission) -> String {
         allowed_tools: mission.allowed_tools.clone(),
         permission: mission.permission.clone(),
         project: mission.project.clone(),
+        max_runtime_secs: mission.max_runtime_secs,
         created_at: mission.created_at,
     };
     let yaml = serde_yml::to_string(&fm).unwrap_or_default();
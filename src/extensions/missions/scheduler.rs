@@ -803,7 +803,34 @@ async fn dispatch_mission_prompt(
         }
     });
 
+    // Watchdog: a run that hangs (a pathological tool call, a wedged
+    // provider stream) holds the IN_FLIGHT claim forever, silently
+    // starving every later trigger. `max_runtime_secs` bounds it — rather
+    // than racing `time::timeout()` against the whole loop future (which
+    // would just drop it, bypassing `is_cancelled()` checkpoints and
+    // leaving delegated `spawn_blocking` subagents running unrecorded),
+    // spawn a sibling timer that goes through the same
+    // `cancel_run_tree` path an explicit user cancellation uses, and let
+    // the loop unwind through its own checkpoints.
+    let watchdog = mission.max_runtime_secs.map(|secs| {
+        let manager = manager.clone();
+        let run_id = run_id.clone();
+        let mission_id = mission.id.clone();
+        tokio::spawn(async move {
+            time::sleep(Duration::from_secs(secs)).await;
+            warn!(
+                "mission '{}' exceeded max_runtime_secs ({}s) — cancelling run {}",
+                mission_id, secs, run_id
+            );
+            let _ = manager.cancel_run_tree(&run_id).await;
+        })
+    });
+
     let result = engine.run_agent_loop(session_id.as_deref()).await;
+
+    if let Some(handle) = watchdog {
+        handle.abort();
+    }
     engine.thinking_tx = None;
     engine.set_run_id(None);
 
@@ -0,0 +1,214 @@
+//! Structural validation for a `SKILL.md` bundle, beyond what frontmatter
+//! parsing alone catches. Run automatically after `skills add`/`init`
+//! (bad skills are rejected instead of silently installed) and exposed
+//! standalone as `linggen skills lint <dir>` for skill authors.
+
+use super::{parse_skill_text, SkillSource};
+use std::path::{Component, Path};
+
+/// Frontmatter files above this size are almost certainly a mistake (a
+/// skill's instructions belong in the body, not a wall of YAML) — flagged
+/// as an error rather than a warning since it usually means a malformed
+/// delimiter swallowed the whole file into the frontmatter half.
+const MAX_SKILL_MD_BYTES: u64 = 256 * 1024;
+
+#[derive(Debug, Default)]
+pub struct LintReport {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl LintReport {
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Reject a relative path that escapes the skill directory (`..` components,
+/// or absolute) — same rule `marketplace::is_safe_zip_path` applies to
+/// extracted ZIP entries, applied here to paths a skill *references*
+/// (`install`, `app.entry`).
+fn is_safe_relative_path(rel_path: &str) -> bool {
+    if rel_path.is_empty() || rel_path.starts_with('/') {
+        return false;
+    }
+    !Path::new(rel_path)
+        .components()
+        .any(|c| matches!(c, Component::ParentDir))
+}
+
+/// Lint an installed skill directory or a bare `SKILL.md`/`<name>.md` file.
+pub fn lint_skill_dir(path: &Path) -> LintReport {
+    let mut report = LintReport::default();
+
+    let (skill_md, skill_dir) = if path.is_dir() {
+        (path.join("SKILL.md"), path.to_path_buf())
+    } else {
+        (
+            path.to_path_buf(),
+            path.parent().map(Path::to_path_buf).unwrap_or_default(),
+        )
+    };
+
+    if !skill_md.exists() {
+        report
+            .errors
+            .push(format!("{} not found", skill_md.display()));
+        return report;
+    }
+
+    if let Ok(meta) = std::fs::metadata(&skill_md) {
+        if meta.len() > MAX_SKILL_MD_BYTES {
+            report.errors.push(format!(
+                "{} is {} bytes, over the {}-byte limit",
+                skill_md.display(),
+                meta.len(),
+                MAX_SKILL_MD_BYTES
+            ));
+        }
+    }
+
+    let text = match std::fs::read_to_string(&skill_md) {
+        Ok(t) => t,
+        Err(e) => {
+            report
+                .errors
+                .push(format!("failed to read {}: {e}", skill_md.display()));
+            return report;
+        }
+    };
+
+    // SkillSource here is discarded after parsing — lint only cares about
+    // the frontmatter and referenced files, not where it will end up.
+    let skill = match parse_skill_text(&text, SkillSource::Global) {
+        Ok(s) => s,
+        Err(e) => {
+            report.errors.push(format!("invalid frontmatter: {e}"));
+            return report;
+        }
+    };
+
+    if skill.name.trim().is_empty() {
+        report.errors.push("`name` is empty".to_string());
+    }
+    if skill.description.trim().is_empty() {
+        report.errors.push("`description` is empty".to_string());
+    }
+
+    if let Some(install) = &skill.install {
+        if !is_safe_relative_path(install) {
+            report.errors.push(format!(
+                "`install: {install}` escapes the skill directory"
+            ));
+        } else if !skill_dir.join(install).exists() {
+            report
+                .errors
+                .push(format!("`install: {install}` does not exist"));
+        }
+    }
+
+    if let Some(app) = &skill.app {
+        if app.launcher != "url" {
+            if !is_safe_relative_path(&app.entry) {
+                report.errors.push(format!(
+                    "`app.entry: {}` escapes the skill directory",
+                    app.entry
+                ));
+            } else if !skill_dir.join(&app.entry).exists() {
+                report
+                    .errors
+                    .push(format!("`app.entry: {}` does not exist", app.entry));
+            }
+        }
+    }
+
+    if skill.content.trim().is_empty() {
+        report
+            .warnings
+            .push("skill body is empty — the model has no instructions to follow".to_string());
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_safe_relative_path_rejects_parent_dir() {
+        assert!(!is_safe_relative_path("../secrets.txt"));
+        assert!(!is_safe_relative_path("scripts/../../secrets.txt"));
+        assert!(!is_safe_relative_path("a/../../b"));
+    }
+
+    #[test]
+    fn is_safe_relative_path_rejects_absolute() {
+        assert!(!is_safe_relative_path("/etc/passwd"));
+    }
+
+    #[test]
+    fn is_safe_relative_path_rejects_empty() {
+        assert!(!is_safe_relative_path(""));
+    }
+
+    #[test]
+    fn is_safe_relative_path_accepts_nested_relative() {
+        assert!(is_safe_relative_path("scripts/install.sh"));
+        assert!(is_safe_relative_path("install.sh"));
+        assert!(is_safe_relative_path("./install.sh"));
+    }
+
+    fn write_skill(dir: &Path, frontmatter_extra: &str) {
+        std::fs::write(
+            dir.join("SKILL.md"),
+            format!(
+                "---\nname: test-skill\ndescription: A test skill\n{frontmatter_extra}\n---\nBody text.\n"
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn lint_skill_dir_rejects_install_escaping_skill_dir() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_skill(tmp.path(), "install: ../../evil.sh");
+
+        let report = lint_skill_dir(tmp.path());
+        assert!(!report.is_ok());
+        assert!(report.errors.iter().any(|e| e.contains("escapes the skill directory")));
+    }
+
+    #[test]
+    fn lint_skill_dir_rejects_absolute_install() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_skill(tmp.path(), "install: /etc/passwd");
+
+        let report = lint_skill_dir(tmp.path());
+        assert!(!report.is_ok());
+        assert!(report.errors.iter().any(|e| e.contains("escapes the skill directory")));
+    }
+
+    #[test]
+    fn lint_skill_dir_rejects_app_entry_escaping_skill_dir() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_skill(
+            tmp.path(),
+            "app:\n  launcher: bash\n  entry: ../../evil.sh",
+        );
+
+        let report = lint_skill_dir(tmp.path());
+        assert!(!report.is_ok());
+        assert!(report.errors.iter().any(|e| e.contains("escapes the skill directory")));
+    }
+
+    #[test]
+    fn lint_skill_dir_accepts_safe_install_that_exists() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_skill(tmp.path(), "install: setup.sh");
+        std::fs::write(tmp.path().join("setup.sh"), "#!/bin/sh\n").unwrap();
+
+        let report = lint_skill_dir(tmp.path());
+        assert!(report.is_ok());
+    }
+}
@@ -1,5 +1,7 @@
 pub use crate::extensions::marketplace;
 
+pub mod lint;
+
 use crate::engine::skill_tool::{SkillParamDef, SkillToolDef};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
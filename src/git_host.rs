@@ -0,0 +1,48 @@
+//! Allowlist of Git hosting hostnames trusted for skill installs (and any
+//! other Git-source ingestion in the marketplace). `github.com`,
+//! `gitlab.com`, and `bitbucket.org` are always trusted; self-hosted
+//! instances (GitHub Enterprise, GitLab CE/EE, Bitbucket Server) must be
+//! added explicitly via `[skills] git_host_allowlist` in `linggen.toml`.
+//!
+//! Set once at startup from `Config::skills.git_host_allowlist`, mirroring
+//! `offline::set_offline`.
+
+use std::sync::OnceLock;
+
+static ALLOWLIST: OnceLock<Vec<String>> = OnceLock::new();
+
+const BUILTIN_HOSTS: &[&str] = &["github.com", "gitlab.com", "bitbucket.org"];
+
+/// Latches the process-wide extra host allowlist. Call once at startup;
+/// later calls are ignored, matching `offline::set_offline`.
+pub fn set_extra_hosts(hosts: Vec<String>) {
+    let _ = ALLOWLIST.set(hosts);
+}
+
+/// Whether `host` (case-insensitive) is trusted for Git-source installs —
+/// one of the built-in hosts, or configured via `[skills] git_host_allowlist`.
+/// Defaults to just the built-in hosts if `set_extra_hosts` was never called
+/// (e.g. in unit tests), matching `offline::is_offline`'s fallback.
+pub fn is_allowed(host: &str) -> bool {
+    BUILTIN_HOSTS.iter().any(|h| h.eq_ignore_ascii_case(host))
+        || ALLOWLIST
+            .get()
+            .is_some_and(|extra| extra.iter().any(|h| h.eq_ignore_ascii_case(host)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_hosts_allowed_without_config() {
+        assert!(is_allowed("github.com"));
+        assert!(is_allowed("GitLab.com"));
+        assert!(is_allowed("bitbucket.org"));
+    }
+
+    #[test]
+    fn unknown_host_rejected_without_allowlist() {
+        assert!(!is_allowed("git.example.com"));
+    }
+}
@@ -4,10 +4,13 @@ use std::sync::OnceLock;
 use std::time::{Duration, SystemTime};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::reload::Handle;
-use tracing_subscriber::{fmt::time::ChronoUtc, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Registry};
+use tracing_subscriber::{
+    fmt::time::ChronoUtc, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer, Registry,
+};
 
 static LOG_GUARD: OnceLock<WorkerGuard> = OnceLock::new();
 static FILTER_HANDLE: OnceLock<Handle<EnvFilter, Registry>> = OnceLock::new();
+static LOG_DIR: OnceLock<PathBuf> = OnceLock::new();
 
 const DEFAULT_RETENTION_DAYS: u64 = 7;
 const LOG_FILE_PREFIX: &str = "linggen";
@@ -16,6 +19,8 @@ pub struct LoggingSettings<'a> {
     pub level: Option<&'a str>,
     pub directory: Option<&'a str>,
     pub retention_days: Option<u64>,
+    /// `"pretty"` (default) or `"json"`.
+    pub format: Option<&'a str>,
 }
 
 /// Build an `EnvFilter` for the given application log level.
@@ -30,6 +35,7 @@ fn build_filter(level: &str) -> EnvFilter {
 
 pub fn setup_tracing_with_settings(settings: LoggingSettings<'_>) -> Result<PathBuf> {
     let log_dir = resolve_log_dir(settings.directory)?;
+    let _ = LOG_DIR.set(log_dir.clone());
     let retention_days = settings.retention_days.unwrap_or(DEFAULT_RETENTION_DAYS).max(1);
     if let Err(e) = cleanup_old_logs(&log_dir, retention_days) {
         eprintln!("Failed to cleanup old logs: {e}");
@@ -45,27 +51,34 @@ pub fn setup_tracing_with_settings(settings: LoggingSettings<'_>) -> Result<Path
 
     // Second-level timestamp precision to keep logs readable.
     let time_format = ChronoUtc::new("%Y-%m-%dT%H:%M:%S".to_string());
+    let json_format = matches!(settings.format, Some("json"));
 
-    let stdout_layer = tracing_subscriber::fmt::layer()
+    let stdout_base = tracing_subscriber::fmt::layer()
         .with_target(false)
         .with_thread_ids(false)
         .with_thread_names(false)
         .with_file(true)
         .with_line_number(true)
-        .with_level(true)
-        .compact()
-        .with_timer(time_format.clone());
+        .with_level(true);
+    let stdout_layer = if json_format {
+        stdout_base.json().with_timer(time_format.clone()).boxed()
+    } else {
+        stdout_base.compact().with_timer(time_format.clone()).boxed()
+    };
 
-    let file_layer = tracing_subscriber::fmt::layer()
+    let file_base = tracing_subscriber::fmt::layer()
         .with_writer(non_blocking)
         .with_target(false)
         .with_thread_ids(false)
         .with_thread_names(false)
         .with_file(true)
         .with_line_number(true)
-        .with_level(true)
-        .compact()
-        .with_timer(time_format);
+        .with_level(true);
+    let file_layer = if json_format {
+        file_base.json().with_timer(time_format).boxed()
+    } else {
+        file_base.compact().with_timer(time_format).boxed()
+    };
 
     // When level is explicitly set, override RUST_LOG; otherwise, use RUST_LOG first, then default
     let filter = if let Some(level) = settings.level {
@@ -112,6 +125,62 @@ fn resolve_log_dir(configured: Option<&str>) -> Result<PathBuf> {
     Ok(dir)
 }
 
+/// Read the tail of today's log file for `/api/logs` — the Tauri shell's own
+/// console only shows the daemon's stdout, not the rotated file (or the
+/// daemon-mode process, which has no attached console at all). `level`
+/// matches the `tracing` level tag in each formatted line (`INFO`, `WARN`,
+/// ...); `None` returns every line. Lines are returned oldest-first, capped
+/// to the last `tail` matches.
+pub fn tail_recent_logs(level: Option<&str>, tail: usize) -> Result<Vec<String>> {
+    let log_dir = LOG_DIR.get().ok_or_else(|| anyhow!("logging not initialized"))?;
+    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    let path = log_dir.join(format!("{LOG_FILE_PREFIX}.{today}"));
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow!("failed to read {}: {e}", path.display()))?;
+
+    let target_level = level.map(|l| l.to_uppercase());
+    let matches: Vec<&str> = contents
+        .lines()
+        .filter(|line| target_level.as_deref().is_none_or(|target| line_matches_level(line, target)))
+        .collect();
+
+    let start = matches.len().saturating_sub(tail);
+    Ok(matches[start..].iter().map(|s| s.to_string()).collect())
+}
+
+/// Matches a level filter against one log line, regardless of which
+/// `[logging] format` produced it: JSON (`{"level":"INFO",...}`) or the
+/// pretty/compact formatter's space-padded `" INFO "` tag. Tried as JSON
+/// first since a JSON line would never contain the padded tag substring.
+fn line_matches_level(line: &str, target: &str) -> bool {
+    if let Ok(value) = serde_json::from_str::<serde_json::Value>(line) {
+        return value
+            .get("level")
+            .and_then(|v| v.as_str())
+            .is_some_and(|l| l.eq_ignore_ascii_case(target));
+    }
+    line.contains(&format!(" {target} "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_matches_level_pretty_format() {
+        let line = "2024-01-01T00:00:00Z  INFO ling: starting up";
+        assert!(line_matches_level(line, "INFO"));
+        assert!(!line_matches_level(line, "ERROR"));
+    }
+
+    #[test]
+    fn line_matches_level_json_format() {
+        let line = r#"{"timestamp":"2024-01-01T00:00:00Z","level":"INFO","fields":{"message":"starting up"}}"#;
+        assert!(line_matches_level(line, "INFO"));
+        assert!(!line_matches_level(line, "ERROR"));
+    }
+}
+
 fn expand_tilde(path: &str) -> PathBuf {
     if let Some(rest) = path.strip_prefix("~/") {
         if let Some(home) = dirs::home_dir() {
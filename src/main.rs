@@ -5,8 +5,11 @@ mod credentials;
 mod engine;
 mod eval;
 mod extensions;
+mod git_host;
 mod logging;
 mod message;
+mod offline;
+mod onboarding;
 mod paths;
 mod prompts;
 mod provider;
@@ -23,7 +26,13 @@ use std::sync::Arc;
 #[derive(Parser, Debug)]
 #[command(name = "ling", version)]
 #[command(about = "Linggen — the root system for AI agents", long_about = None)]
+#[command(after_help = cli::exit_code::HELP_TEXT)]
 struct Cli {
+    /// Print only a final JSON result line ({"ok", "code", "exit_code", "message"})
+    /// instead of the human-readable "Error: ..." banner on failure.
+    #[arg(long, global = true, default_value_t = false)]
+    quiet: bool,
+
     /// Workspace root. If omitted, detects by walking up for .git.
     #[arg(long, global = true)]
     root: Option<std::path::PathBuf>,
@@ -96,9 +105,19 @@ enum Command {
     /// Set up ~/.linggen/ environment (directories, agents, config, skills)
     Init,
     /// Install/update the ling binary to latest
-    Install,
+    Install {
+        /// Release channel to install from (stable, beta, nightly). Persists
+        /// as the default for future `install`/`update` runs.
+        #[arg(long)]
+        channel: Option<String>,
+    },
     /// Update the ling binary to latest
-    Update,
+    Update {
+        /// Release channel to update from (stable, beta, nightly). Persists
+        /// as the default for future `install`/`update` runs.
+        #[arg(long)]
+        channel: Option<String>,
+    },
     /// Manage skills
     Skills {
         #[command(subcommand)]
@@ -174,21 +193,76 @@ enum SkillsAction {
     },
     /// List installed skills
     List,
+    /// Re-fetch installed skills from their recorded install origin
+    Update {
+        /// Skill name (omit with --all to update every installed skill)
+        name: Option<String>,
+
+        /// Update every installed skill that has a recorded origin
+        #[arg(long, default_value_t = false)]
+        all: bool,
+    },
+    /// Install exactly what linggen-skills.lock says (project scope)
+    Sync {
+        /// Fail if an installed skill's content no longer matches the
+        /// lockfile, instead of refreshing it
+        #[arg(long, default_value_t = false)]
+        frozen: bool,
+    },
     /// Search the marketplace
     Search {
         /// Search query
         query: String,
     },
+    /// Validate a skill directory without installing it
+    Lint {
+        /// Path to the skill directory (or a bare SKILL.md/<name>.md file)
+        dir: std::path::PathBuf,
+    },
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() {
+    let cli = Cli::parse();
+    let quiet = cli.quiet;
+
+    match run(cli).await {
+        Ok(()) => {
+            if quiet {
+                print_quiet_result(cli::exit_code::ExitCode::Ok, "ok");
+            }
+        }
+        Err(e) => {
+            let code = cli::exit_code::classify(&e);
+            if quiet {
+                print_quiet_result(code, &e.to_string());
+            } else {
+                eprintln!("Error: {e:#}");
+            }
+            std::process::exit(code.code());
+        }
+    }
+}
+
+/// Print the single JSON line `--quiet` promises: `{ok, code, exit_code, message}`.
+fn print_quiet_result(code: cli::exit_code::ExitCode, message: &str) {
+    println!(
+        "{}",
+        serde_json::json!({
+            "ok": code == cli::exit_code::ExitCode::Ok,
+            "code": code.to_string(),
+            "exit_code": code.code(),
+            "message": message,
+        })
+    );
+}
+
+async fn run(cli: Cli) -> Result<()> {
     let (config, config_path) = Config::load_with_path().unwrap_or_else(|e| {
         eprintln!("Warning: failed to load config, using defaults: {e}");
         (Config::default(), None)
     });
 
-    let cli = Cli::parse();
     let global_root = cli.root;
     let global_host = cli.host;
     let global_port = cli.port;
@@ -197,6 +271,10 @@ async fn main() -> Result<()> {
         crate::paths::set_skills_dir_override(dir);
     }
 
+    crate::offline::set_offline(config.offline);
+    crate::provider::trace::set_trace_enabled(config.agent.llm_trace_enabled);
+    crate::git_host::set_extra_hosts(config.skills.git_host_allowlist.clone());
+
     // Lightweight subcommands — no tracing/AgentManager needed.
     match &cli.cmd {
         Some(Command::Doctor) => {
@@ -211,8 +289,9 @@ async fn main() -> Result<()> {
         Some(Command::Init) => {
             return cli::init::run(true, None).await;
         }
-        Some(Command::Install) | Some(Command::Update) => {
-            return cli::self_update::run().await;
+        Some(Command::Install { channel }) | Some(Command::Update { channel }) => {
+            let channel = channel.as_deref().map(|c| c.parse()).transpose()?;
+            return cli::self_update::run(channel).await;
         }
         Some(Command::Account { action }) => {
             return match action {
@@ -276,6 +355,12 @@ async fn main() -> Result<()> {
                     global: *global,
                 },
                 SkillsAction::List => cli::skills_cmd::SkillsAction::List,
+                SkillsAction::Update { name, all } => cli::skills_cmd::SkillsAction::Update {
+                    name: name.clone(),
+                    all: *all,
+                },
+                SkillsAction::Sync { frozen } => cli::skills_cmd::SkillsAction::Sync { frozen: *frozen },
+                SkillsAction::Lint { dir } => cli::skills_cmd::SkillsAction::Lint { dir: dir.clone() },
                 SkillsAction::Search { query } => cli::skills_cmd::SkillsAction::Search {
                     query: query.clone(),
                 },
@@ -315,6 +400,7 @@ async fn main() -> Result<()> {
         level: config.logging.level.as_deref(),
         directory: config.logging.directory.as_deref(),
         retention_days: config.logging.retention_days,
+        format: config.logging.format.as_deref(),
     }) {
         Ok(path) => Some(path),
         Err(err) => {
@@ -442,8 +528,8 @@ async fn main() -> Result<()> {
         | Some(Command::Stop)
         | Some(Command::Status)
         | Some(Command::Init)
-        | Some(Command::Install)
-        | Some(Command::Update)
+        | Some(Command::Install { .. })
+        | Some(Command::Update { .. })
         | Some(Command::Skills { .. })
         | Some(Command::Auth { .. })
         | Some(Command::Account { .. }) => unreachable!(),
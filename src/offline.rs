@@ -0,0 +1,42 @@
+//! Central offline-mode switch.
+//!
+//! Set once at startup from `Config::offline`, then checked at every call
+//! site that reaches the network: web search/fetch, the self-updater's
+//! manifest fetch, and skill marketplace lookups/installs. Locally present
+//! models, skills, and data keep working; only outbound calls short-circuit,
+//! with a clear error instead of a hang or a confusing transport failure.
+
+use std::sync::OnceLock;
+
+static OFFLINE: OnceLock<bool> = OnceLock::new();
+
+/// Latches the process-wide offline flag. Call once at startup; later calls
+/// are ignored, matching `paths::set_skills_dir_override`.
+pub fn set_offline(offline: bool) {
+    let _ = OFFLINE.set(offline);
+}
+
+/// Whether offline mode is active. Defaults to `false` if `set_offline` was
+/// never called (e.g. in unit tests).
+pub fn is_offline() -> bool {
+    OFFLINE.get().copied().unwrap_or(false)
+}
+
+/// Standard error for a network call short-circuited by offline mode.
+/// `what` names the thing that was skipped, e.g. "web search" or
+/// "skill marketplace search".
+pub fn offline_error(what: &str) -> anyhow::Error {
+    anyhow::anyhow!("{what} is unavailable: Linggen is running in offline mode")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offline_error_names_the_call() {
+        let err = offline_error("web search");
+        assert!(err.to_string().contains("web search"));
+        assert!(err.to_string().contains("offline"));
+    }
+}
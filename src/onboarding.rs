@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+// ---------------------------------------------------------------------------
+// Persisted format: ~/.linggen/config/onboarding.json
+// ---------------------------------------------------------------------------
+
+/// First-run setup milestones, served through `/api/onboarding/*` so every
+/// surface that reads them shows the same guidance for a broken or
+/// half-finished install instead of guessing independently. `ling doctor`
+/// does not read them.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingStep {
+    /// At least one model is configured (`linggen.toml` or credentials.json).
+    ModelConfigured,
+    /// The `/mcp` endpoint has been added to at least one external client.
+    McpConfigured,
+    /// The user completed their first chat turn.
+    FirstChatCompleted,
+    /// A skill beyond the built-ins has been installed.
+    SkillInstalled,
+}
+
+impl OnboardingStep {
+    pub const ALL: [OnboardingStep; 4] = [
+        OnboardingStep::ModelConfigured,
+        OnboardingStep::McpConfigured,
+        OnboardingStep::FirstChatCompleted,
+        OnboardingStep::SkillInstalled,
+    ];
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+pub struct OnboardingState {
+    /// Steps completed via `advance`.
+    #[serde(default)]
+    completed: Vec<OnboardingStep>,
+    /// Steps the user explicitly dismissed via `skip` — distinct from
+    /// `completed` so the UI can still show them as "skipped" rather than
+    /// implying the work was done.
+    #[serde(default)]
+    skipped: Vec<OnboardingStep>,
+}
+
+impl OnboardingState {
+    /// Load from `~/.linggen/config/onboarding.json`. Returns a fresh
+    /// (all-pending) state if missing or invalid.
+    pub fn load(file: &Path) -> Self {
+        if !file.exists() {
+            return Self::default();
+        }
+        match std::fs::read_to_string(file) {
+            Ok(content) => match serde_json::from_str::<OnboardingState>(&content) {
+                Ok(state) => state,
+                Err(e) => {
+                    warn!("Failed to parse onboarding.json: {}", e);
+                    Self::default()
+                }
+            },
+            Err(e) => {
+                warn!("Failed to read onboarding.json: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Save to disk. Creates parent directories if needed.
+    pub fn save(&self, file: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(file, json)?;
+        Ok(())
+    }
+
+    pub fn advance(&mut self, step: OnboardingStep) {
+        self.skipped.retain(|s| *s != step);
+        if !self.completed.contains(&step) {
+            self.completed.push(step);
+        }
+    }
+
+    pub fn skip(&mut self, step: OnboardingStep) {
+        if !self.completed.contains(&step) && !self.skipped.contains(&step) {
+            self.skipped.push(step);
+        }
+    }
+
+    pub fn is_done(&self, step: OnboardingStep) -> bool {
+        self.completed.contains(&step) || self.skipped.contains(&step)
+    }
+
+    /// A fresh install with nothing configured or dismissed yet.
+    pub fn is_broken_install(&self) -> bool {
+        self.completed.is_empty() && self.skipped.is_empty()
+    }
+}
+
+/// Default onboarding state file path: `~/.linggen/config/onboarding.json`.
+pub fn onboarding_file() -> PathBuf {
+    crate::paths::config_dir().join("onboarding.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_onboarding_roundtrip() {
+        let tmp = std::env::temp_dir().join("linggen_onboarding_test");
+        let _ = std::fs::remove_dir_all(&tmp);
+        std::fs::create_dir_all(&tmp).unwrap();
+        let file = tmp.join("onboarding.json");
+
+        let mut state = OnboardingState::default();
+        state.advance(OnboardingStep::ModelConfigured);
+        state.skip(OnboardingStep::SkillInstalled);
+        state.save(&file).unwrap();
+
+        let loaded = OnboardingState::load(&file);
+        assert!(loaded.is_done(OnboardingStep::ModelConfigured));
+        assert!(loaded.is_done(OnboardingStep::SkillInstalled));
+        assert!(!loaded.is_done(OnboardingStep::McpConfigured));
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn test_advance_overrides_skip() {
+        let mut state = OnboardingState::default();
+        state.skip(OnboardingStep::FirstChatCompleted);
+        state.advance(OnboardingStep::FirstChatCompleted);
+        assert!(state.completed.contains(&OnboardingStep::FirstChatCompleted));
+        assert!(!state.skipped.contains(&OnboardingStep::FirstChatCompleted));
+    }
+
+    #[test]
+    fn test_load_missing_file_is_fresh() {
+        let state = OnboardingState::load(Path::new("/nonexistent/onboarding.json"));
+        assert!(state.is_broken_install());
+    }
+}
@@ -16,6 +16,7 @@ pub mod ollama;
 pub mod openai;
 pub mod proxy_provider;
 pub mod routing;
+pub(crate) mod trace;
 
 /// Render a mid-stream body read error with a truthful name: reqwest wraps
 /// a `read_timeout` firing between chunks as "error decoding response body",
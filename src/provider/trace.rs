@@ -0,0 +1,136 @@
+//! Opt-in LLM call tracing for debugging bad generations (garbage profile
+//! output, misclassified intent, ...). Off by default
+//! (`AgentConfig::llm_trace_enabled`); when on, every completed
+//! `stream_with_thinking_model` / `stream_with_tool_calling` call appends
+//! one row to `~/.linggen/traces/llm.jsonl`.
+//!
+//! Append-only JSONL, trimmed back to the newest `MAX_TRACE_ROWS` rows once
+//! every `TRIM_EVERY` appends, so between trims it can hold more than
+//! `MAX_TRACE_ROWS` rows — same shape as `mission/runs.jsonl`, just for LLM calls
+//! instead of mission runs.
+
+use crate::message::ChatMessage;
+use crate::provider::models::TokenUsage;
+use serde::Serialize;
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+/// Rows kept by a trim; older rows are dropped when a trim runs (see
+/// `TRIM_EVERY`), not as soon as the file exceeds this many entries.
+const MAX_TRACE_ROWS: usize = 2000;
+
+/// Only worth re-reading the whole file to trim it once every this many
+/// appends — trimming on every call would put the same O(n) rewrite back
+/// on the hot path the `append` switch was meant to get off of.
+const TRIM_EVERY: usize = 200;
+
+static TRACE_ENABLED: OnceLock<bool> = OnceLock::new();
+static APPENDS_SINCE_TRIM: AtomicUsize = AtomicUsize::new(0);
+
+/// Latches the process-wide trace flag from `AgentConfig::llm_trace_enabled`.
+/// Call once at startup, matching `offline::set_offline`.
+pub fn set_trace_enabled(enabled: bool) {
+    let _ = TRACE_ENABLED.set(enabled);
+}
+
+/// Whether LLM call tracing is active. Defaults to `false` if
+/// `set_trace_enabled` was never called (e.g. in unit tests).
+pub(crate) fn is_trace_enabled() -> bool {
+    TRACE_ENABLED.get().copied().unwrap_or(false)
+}
+
+#[derive(Serialize)]
+struct TraceRow {
+    ts: u64,
+    model_id: String,
+    prompt: Vec<ChatMessage>,
+    response: String,
+    latency_ms: u128,
+    token_usage: Option<TokenUsage>,
+    error: Option<String>,
+}
+
+fn trace_path() -> std::path::PathBuf {
+    crate::paths::linggen_home().join("traces").join("llm.jsonl")
+}
+
+/// Append one trace row. A no-op unless `set_trace_enabled(true)` was
+/// called at startup — this stays a single bool check away from every call
+/// site when tracing is off. Failures are logged, not propagated — a
+/// broken trace file must never break a chat turn.
+///
+/// The actual write happens on a blocking thread (`spawn_blocking`), not
+/// inline on the async streaming task — same reasoning as everywhere else
+/// in this codebase that touches the filesystem from async code.
+pub(crate) fn record_llm_trace(
+    model_id: &str,
+    prompt: &[ChatMessage],
+    response: &str,
+    latency_ms: u128,
+    token_usage: Option<&TokenUsage>,
+    error: Option<&str>,
+) {
+    if !is_trace_enabled() {
+        return;
+    }
+    let row = TraceRow {
+        ts: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        model_id: model_id.to_string(),
+        prompt: prompt.to_vec(),
+        response: response.to_string(),
+        latency_ms,
+        token_usage: token_usage.cloned(),
+        error: error.map(String::from),
+    };
+    tokio::task::spawn_blocking(move || {
+        if let Err(e) = append_row(&row) {
+            tracing::warn!("failed to write LLM trace row: {}", e);
+        }
+    });
+}
+
+/// Appends one JSONL line — `OpenOptions::append`, no read-modify-write of
+/// the whole file (matches `append_mission_run`). Trimming to
+/// `MAX_TRACE_ROWS` is done lazily, only every `TRIM_EVERY` appends, since
+/// it does require rewriting the file and isn't worth doing on every call.
+fn append_row(row: &TraceRow) -> std::io::Result<()> {
+    let path = trace_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let line = serde_json::to_string(row)?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+    writeln!(file, "{line}")?;
+    drop(file);
+
+    if APPENDS_SINCE_TRIM.fetch_add(1, Ordering::Relaxed) + 1 >= TRIM_EVERY {
+        APPENDS_SINCE_TRIM.store(0, Ordering::Relaxed);
+        trim_trace_file(&path)?;
+    }
+    Ok(())
+}
+
+/// Drops the oldest rows once the file exceeds `MAX_TRACE_ROWS` lines.
+/// Not safe against concurrent trims racing a concurrent append (same as
+/// the append itself has no cross-process lock) — acceptable for a
+/// best-effort debug log, not for anything durability-sensitive.
+fn trim_trace_file(path: &std::path::Path) -> std::io::Result<()> {
+    let content = std::fs::read_to_string(path)?;
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.len() <= MAX_TRACE_ROWS {
+        return Ok(());
+    }
+    let start = lines.len() - MAX_TRACE_ROWS;
+    let mut file = std::fs::File::create(path)?;
+    for line in &lines[start..] {
+        writeln!(file, "{line}")?;
+    }
+    Ok(())
+}
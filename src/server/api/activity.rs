@@ -0,0 +1,55 @@
+//! `GET /api/activity` — a lightweight "what's the backend doing right now"
+//! summary for the desktop tray tooltip/menu, so it can show something like
+//! "2 agents running" instead of a static label without polling the full
+//! `/api/status` payload (session counts, model usage history, ...) on a
+//! timer.
+//!
+//! There's no per-job percentage or file-watcher event feed to report:
+//! agent runs don't track fractional progress (see `AgentRunRecord`), and
+//! this repo has no filesystem watcher — `Read`/`Glob`/`Grep` hit the live
+//! workspace directly. Those fields are omitted rather than faked.
+
+use crate::engine::agent::AgentRunStatus;
+use crate::server::ServerState;
+use axum::{extract::State, response::IntoResponse, Json};
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(Serialize)]
+pub(crate) struct ActiveJob {
+    run_id: String,
+    agent_id: String,
+    session_id: String,
+    detail: Option<String>,
+    started_at: u64,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ActivityResponse {
+    active_jobs: Vec<ActiveJob>,
+}
+
+pub(crate) async fn get_activity_api(State(state): State<Arc<ServerState>>) -> impl IntoResponse {
+    // `project_root` is unused by `list_agent_runs` (runs are indexed by
+    // session, not project) — pass an empty path.
+    let runs = state
+        .manager
+        .list_agent_runs(&PathBuf::new(), None)
+        .await
+        .unwrap_or_default();
+
+    let active_jobs = runs
+        .into_iter()
+        .filter(|r| r.status == AgentRunStatus::Running)
+        .map(|r| ActiveJob {
+            run_id: r.run_id,
+            agent_id: r.agent_id,
+            session_id: r.session_id,
+            detail: r.detail,
+            started_at: r.started_at,
+        })
+        .collect();
+
+    Json(ActivityResponse { active_jobs })
+}
@@ -409,6 +409,9 @@ pub(crate) async fn list_agent_files_api(
     State(state): State<Arc<ServerState>>,
     Query(query): Query<ProjectQuery>,
 ) -> impl IntoResponse {
+    if let Err(err) = query.validate() {
+        return err.into_response();
+    }
     let root = canonical_project_root(&query.project_root);
     match state.manager.list_agent_specs(&root).await {
         Ok(entries) => {
@@ -0,0 +1,215 @@
+//! `POST /api/context-pack` — bundle a query or an explicit file list into
+//! one downloadable markdown document, for pasting into another tool's
+//! chat window or attaching to a ticket: a fenced JSON manifest up top
+//! (paths, byte/line counts, what got capped) followed by one fenced code
+//! block per file. Size-capped per file and overall, with a best-effort
+//! secret redaction pass over every file's content before it's included.
+
+use crate::server::api::workspace::{expand_project_root, resolve_in_root};
+use crate::server::error::ApiError;
+use crate::server::ServerState;
+use axum::{
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use ignore::WalkBuilder;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, OnceLock};
+
+/// Per-file cap — enough for most source files without one huge file
+/// crowding out everything else.
+const MAX_FILE_BYTES: usize = 64 * 1024;
+/// Overall pack cap — keeps the artifact pasteable into a chat window.
+const MAX_TOTAL_BYTES: usize = 512 * 1024;
+/// When selecting files by `query` rather than an explicit list, how many
+/// matches to include at most.
+const MAX_QUERY_MATCHES: usize = 20;
+
+#[derive(Deserialize)]
+pub(crate) struct ContextPackRequest {
+    project_root: String,
+    /// Explicit relative file paths to include, in order.
+    #[serde(default)]
+    files: Vec<String>,
+    /// When `files` is empty, select files whose relative path or content
+    /// contains this substring (case-insensitive).
+    query: Option<String>,
+}
+
+#[derive(Serialize)]
+struct SourceEntry {
+    path: String,
+    bytes: usize,
+    lines: usize,
+    truncated: bool,
+}
+
+#[derive(Serialize)]
+struct Manifest {
+    query: Option<String>,
+    sources: Vec<SourceEntry>,
+    omitted: Vec<String>,
+    total_bytes: usize,
+}
+
+/// Select up to `MAX_QUERY_MATCHES` files under `root` whose relative path
+/// or content contains `query` (case-insensitive). Skips anything git
+/// ignores, hidden dirs, and non-UTF-8 files.
+fn find_files_by_query(root: &std::path::Path, query: &str) -> Vec<String> {
+    let needle = query.to_lowercase();
+    let mut matches = Vec::new();
+    let walker = WalkBuilder::new(root).standard_filters(true).hidden(true).build();
+    for entry in walker {
+        if matches.len() >= MAX_QUERY_MATCHES {
+            break;
+        }
+        let Ok(entry) = entry else { continue };
+        if entry.file_type().is_some_and(|t| !t.is_file()) {
+            continue;
+        }
+        let abs_path = entry.path();
+        let Ok(rel) = abs_path.strip_prefix(root) else { continue };
+        let rel_str = rel.to_string_lossy().to_string();
+        if rel_str.is_empty() {
+            continue;
+        }
+        let path_hit = rel_str.to_lowercase().contains(&needle);
+        let content_hit = !path_hit
+            && std::fs::read_to_string(abs_path)
+                .map(|c| c.to_lowercase().contains(&needle))
+                .unwrap_or(false);
+        if path_hit || content_hit {
+            matches.push(rel_str);
+        }
+    }
+    matches
+}
+
+/// Best-effort secret redaction: a handful of common credential shapes
+/// (provider API key prefixes, AWS access keys, bearer tokens, PEM private
+/// key blocks). Not a substitute for not committing secrets in the first
+/// place — just cheap insurance before this leaves the machine.
+fn redact_secrets(content: &str) -> String {
+    static PATTERNS: OnceLock<Vec<Regex>> = OnceLock::new();
+    let patterns = PATTERNS.get_or_init(|| {
+        vec![
+            Regex::new(r"sk-[A-Za-z0-9]{16,}").unwrap(),
+            Regex::new(r"ghp_[A-Za-z0-9]{20,}").unwrap(),
+            Regex::new(r"AKIA[0-9A-Z]{16}").unwrap(),
+            Regex::new(r"(?i)bearer\s+[A-Za-z0-9\-_.]{16,}").unwrap(),
+            Regex::new(r"(?is)-----BEGIN [A-Z ]*PRIVATE KEY-----.*?-----END [A-Z ]*PRIVATE KEY-----").unwrap(),
+        ]
+    });
+    let mut redacted = content.to_string();
+    for pattern in patterns {
+        redacted = pattern.replace_all(&redacted, "[REDACTED]").into_owned();
+    }
+    redacted
+}
+
+/// Truncate `s` to at most `max_bytes`, backing off to the nearest char
+/// boundary so multi-byte UTF-8 sequences aren't split.
+fn truncate_to_byte_limit(s: &str, max_bytes: usize) -> &str {
+    if s.len() <= max_bytes {
+        return s;
+    }
+    let mut end = max_bytes;
+    while end > 0 && !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+fn lang_hint(path: &str) -> &'static str {
+    match path.rsplit('.').next().unwrap_or("") {
+        "rs" => "rust",
+        "ts" | "tsx" => "typescript",
+        "js" | "jsx" => "javascript",
+        "py" => "python",
+        "toml" => "toml",
+        "json" => "json",
+        "md" => "markdown",
+        "sh" => "bash",
+        "yml" | "yaml" => "yaml",
+        _ => "",
+    }
+}
+
+pub(crate) async fn post_context_pack(
+    State(_state): State<Arc<ServerState>>,
+    Json(req): Json<ContextPackRequest>,
+) -> Response {
+    let project_root = expand_project_root(&req.project_root);
+    let Ok(canonical_root) = project_root.canonicalize() else {
+        return ApiError::NotFound("project_root not found".into()).into_response();
+    };
+
+    let rel_paths: Vec<String> = if !req.files.is_empty() {
+        req.files.clone()
+    } else if let Some(query) = &req.query {
+        find_files_by_query(&canonical_root, query)
+    } else {
+        return ApiError::invalid_field("files", "must provide files or query").into_response();
+    };
+
+    let mut sources = Vec::new();
+    let mut omitted = Vec::new();
+    let mut body = String::new();
+    let mut total_bytes = 0usize;
+
+    for rel_path in &rel_paths {
+        let Some(full_path) = resolve_in_root(&canonical_root, rel_path) else {
+            omitted.push(rel_path.clone());
+            continue;
+        };
+        let Ok(content) = std::fs::read_to_string(&full_path) else {
+            omitted.push(rel_path.clone());
+            continue;
+        };
+        if total_bytes >= MAX_TOTAL_BYTES {
+            omitted.push(rel_path.clone());
+            continue;
+        }
+
+        let truncated = content.len() > MAX_FILE_BYTES;
+        let mut slice = truncate_to_byte_limit(&content, MAX_FILE_BYTES).to_string();
+        if total_bytes + slice.len() > MAX_TOTAL_BYTES {
+            let remaining = MAX_TOTAL_BYTES.saturating_sub(total_bytes);
+            slice = truncate_to_byte_limit(&slice, remaining).to_string();
+        }
+        let redacted = redact_secrets(&slice);
+        total_bytes += redacted.len();
+
+        sources.push(SourceEntry {
+            path: rel_path.clone(),
+            bytes: redacted.len(),
+            lines: redacted.lines().count(),
+            truncated,
+        });
+
+        body.push_str(&format!("\n## {rel_path}\n\n```{}\n{}\n```\n", lang_hint(rel_path), redacted));
+    }
+
+    let manifest = Manifest {
+        query: req.query.clone(),
+        sources,
+        omitted,
+        total_bytes,
+    };
+    let manifest_json = serde_json::to_string_pretty(&manifest).unwrap_or_else(|_| "{}".to_string());
+
+    let markdown = format!("# Context Pack\n\n```json\n{manifest_json}\n```\n{body}");
+
+    (
+        axum::http::StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "text/markdown; charset=utf-8"),
+            (header::CONTENT_DISPOSITION, "attachment; filename=\"context-pack.md\""),
+        ],
+        markdown,
+    )
+        .into_response()
+}
@@ -0,0 +1,60 @@
+//! `GET /api/events` — server-sent events for the desktop shell and CLI, so
+//! they can wait for readiness and watch job lifecycle without polling
+//! `/api/status` on a timer. Taps the same `events_tx` broadcast bus the
+//! WebRTC data channels forward to the web UI, filtered down to the coarse,
+//! infrequent events a status bar or CLI actually needs — not the per-token
+//! chat firehose.
+//!
+//! There's no local model download/load step to report progress on: every
+//! model is a remote API provider dialed per-request (see
+//! `provider::models::ModelManager`), so "model download progress" has
+//! nothing to emit here.
+
+use crate::server::{ServerEvent, ServerState};
+use axum::{
+    extract::State,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures_util::stream::Stream;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt as _;
+
+/// Coarse event names an `/api/events` consumer can filter on. Everything
+/// else (tokens, text segments, content blocks, ...) is dropped — those
+/// belong on the WebRTC data channel, not a polling-replacement SSE stream.
+fn sse_event_name(event: &ServerEvent) -> Option<&'static str> {
+    match event {
+        ServerEvent::StateUpdated => Some("state_updated"),
+        ServerEvent::AgentStatus { .. } => Some("agent_status"),
+        ServerEvent::Outcome { .. } => Some("job_outcome"),
+        ServerEvent::MissionTriggered { .. } => Some("mission_triggered"),
+        ServerEvent::Notification(_) => Some("notification"),
+        ServerEvent::SessionCreated { .. } => Some("session_created"),
+        _ => None,
+    }
+}
+
+pub(crate) async fn get_events_api(
+    State(state): State<Arc<ServerState>>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let rx = state.events_tx.subscribe();
+    let stream = BroadcastStream::new(rx).filter_map(|item| {
+        let event = match item {
+            Ok(event) => event,
+            // A slow consumer missed some events — tell it to resync via
+            // /api/status rather than silently gapping the stream.
+            Err(tokio_stream::wrappers::errors::BroadcastStreamRecvError::Lagged(n)) => {
+                return Some(Ok(Event::default().event("resync").data(
+                    serde_json::json!({ "lagged_count": n }).to_string(),
+                )));
+            }
+        };
+        let name = sse_event_name(&event)?;
+        let data = serde_json::to_string(&event).unwrap_or_else(|_| "null".to_string());
+        Some(Ok(Event::default().event(name).data(data)))
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
@@ -0,0 +1,60 @@
+//! `GET /api/logs` — recent backend log lines for the desktop shell, since
+//! the Tauri console only shows stdout and the headless daemon has no
+//! attached console at all. `ling doctor` does not call it.
+//!
+//! `POST /api/logs/level` — change the tracing filter at runtime, without a
+//! restart, for a sidecar deployment where editing `linggen.toml` and
+//! restarting the daemon isn't convenient.
+
+use axum::{
+    extract::Query,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_TAIL: usize = 200;
+const MAX_TAIL: usize = 5000;
+const VALID_LEVELS: &[&str] = &["trace", "debug", "info", "warn", "error"];
+
+#[derive(Deserialize)]
+pub(crate) struct LogsQuery {
+    level: Option<String>,
+    tail: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct LogsResponse {
+    lines: Vec<String>,
+}
+
+pub(crate) async fn get_logs_api(Query(query): Query<LogsQuery>) -> Response {
+    let tail = query.tail.unwrap_or(DEFAULT_TAIL).min(MAX_TAIL);
+    match crate::logging::tail_recent_logs(query.level.as_deref(), tail) {
+        Ok(lines) => Json(LogsResponse { lines }).into_response(),
+        Err(e) => (
+            axum::http::StatusCode::SERVICE_UNAVAILABLE,
+            format!("{e:#}"),
+        )
+            .into_response(),
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SetLevelRequest {
+    level: String,
+}
+
+pub(crate) async fn post_logs_level(Json(req): Json<SetLevelRequest>) -> Response {
+    let level = req.level.to_ascii_lowercase();
+    if !VALID_LEVELS.contains(&level.as_str()) {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("invalid level '{level}', expected one of {VALID_LEVELS:?}"),
+        )
+            .into_response();
+    }
+    crate::logging::set_log_level(&level);
+    Json(serde_json::json!({ "ok": true, "level": level })).into_response()
+}
@@ -1,3 +1,4 @@
+use crate::server::error::ApiError;
 use crate::server::{ServerEvent, ServerState};
 use crate::extensions::skills::marketplace::{self, SkillScope};
 use crate::extensions::skills;
@@ -32,6 +33,15 @@ pub(crate) struct InstallRequest {
     source: Option<String>,
 }
 
+impl InstallRequest {
+    fn validate(&self) -> Result<(), ApiError> {
+        if self.name.trim().is_empty() {
+            return Err(ApiError::invalid_field("name", "must not be empty"));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Deserialize)]
 pub(crate) struct UninstallRequest {
     name: String,
@@ -39,6 +49,15 @@ pub(crate) struct UninstallRequest {
     project_root: Option<String>,
 }
 
+impl UninstallRequest {
+    fn validate(&self) -> Result<(), ApiError> {
+        if self.name.trim().is_empty() {
+            return Err(ApiError::invalid_field("name", "must not be empty"));
+        }
+        Ok(())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Handlers
 // ---------------------------------------------------------------------------
@@ -64,6 +83,9 @@ pub(crate) async fn marketplace_install(
     State(state): State<Arc<ServerState>>,
     Json(req): Json<InstallRequest>,
 ) -> impl IntoResponse {
+    if let Err(err) = req.validate() {
+        return err.into_response();
+    }
     let scope = req.scope.unwrap_or_default();
     let project_root_path = req.project_root.as_deref().map(Path::new);
 
@@ -101,6 +123,9 @@ pub(crate) async fn marketplace_uninstall(
     State(state): State<Arc<ServerState>>,
     Json(req): Json<UninstallRequest>,
 ) -> impl IntoResponse {
+    if let Err(err) = req.validate() {
+        return err.into_response();
+    }
     let project_root_path = req.project_root.as_deref().map(Path::new);
 
     // Look up the skill's actual source to resolve the correct directory.
@@ -1,4 +1,5 @@
 use crate::extensions::missions::{self, MissionDraft, MissionPermission};
+use crate::server::error::ApiError;
 use crate::server::{ServerEvent, ServerState};
 use axum::{
     extract::{Path, Query, State},
@@ -80,33 +81,25 @@ pub(crate) async fn create_mission(
     Json(req): Json<CreateMissionRequest>,
 ) -> impl IntoResponse {
     if let Err(e) = missions::validate_cron(&req.schedule) {
-        return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+        return ApiError::invalid_field("schedule", e.to_string()).into_response();
     }
     if let Some(ref m) = req.permission_mode {
         if let Err(e) = validate_mode(m) {
-            return (StatusCode::BAD_REQUEST, e).into_response();
+            return ApiError::invalid_field("permission_mode", e).into_response();
         }
     }
 
     // Legacy mode handling — kept to absorb old UI payloads.
     let mode = req.mode.as_deref().unwrap_or("agent");
     if mode == "app" {
-        return (
-            StatusCode::BAD_REQUEST,
-            "mode: app is no longer supported".to_string(),
-        )
-            .into_response();
+        return ApiError::invalid_field("mode", "app is no longer supported").into_response();
     }
 
     let prompt = req.prompt.unwrap_or_default();
     let prompt_is_empty = prompt.trim().is_empty();
 
     if mode == "agent" && prompt_is_empty {
-        return (
-            StatusCode::BAD_REQUEST,
-            "Mission requires a prompt body".to_string(),
-        )
-            .into_response();
+        return ApiError::invalid_field("prompt", "Mission requires a prompt body").into_response();
     }
 
     // Resolve permission block: convert (mode, paths) from the API into
@@ -212,20 +205,16 @@ pub(crate) async fn update_mission(
 ) -> impl IntoResponse {
     if let Some(ref s) = req.schedule {
         if let Err(e) = missions::validate_cron(s) {
-            return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+            return ApiError::invalid_field("schedule", e.to_string()).into_response();
         }
     }
     if let Some(ref m) = req.permission_mode {
         if let Err(e) = validate_mode(m) {
-            return (StatusCode::BAD_REQUEST, e).into_response();
+            return ApiError::invalid_field("permission_mode", e).into_response();
         }
     }
     if req.mode.as_deref() == Some("app") {
-        return (
-            StatusCode::BAD_REQUEST,
-            "mode: app is no longer supported".to_string(),
-        )
-            .into_response();
+        return ApiError::invalid_field("mode", "app is no longer supported").into_response();
     }
 
     // Unwrap Option<Option<String>> for name; other Option<Option<T>> fields
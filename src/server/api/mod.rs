@@ -2,11 +2,16 @@
 //! `/api/*` endpoints; `server::mod` wires them into the Axum router.
 
 pub(super) mod account;
+pub(super) mod activity;
 pub(super) mod agents;
 pub(super) mod config;
+pub(super) mod context_pack;
 pub(super) mod dj;
+pub(super) mod events_sse;
+pub(super) mod logs;
 pub(super) mod marketplace;
 pub(super) mod media;
+pub(super) mod onboarding;
 pub(super) mod pair;
 pub(super) mod missions;
 pub(super) mod permissions;
@@ -16,13 +21,19 @@ pub(super) mod skills;
 pub(super) mod status;
 pub(super) mod storage;
 pub(super) mod topic;
+pub(super) mod traces;
 pub(super) mod tts;
 pub(super) mod workspace;
 pub(super) mod yinyue;
 
+use crate::server::error::ApiError;
 use serde::Deserialize;
 use std::path::PathBuf;
 
+/// `limit` above this is rejected rather than silently served — keeps a
+/// single request from paginating through an entire session/skill list.
+const MAX_PAGE_LIMIT: usize = 1000;
+
 /// Shared request shape for project-scoped GET endpoints with pagination.
 /// Used by `list_sessions`, `list_skill_files_api`, `list_agent_files_api`.
 #[derive(Deserialize)]
@@ -36,6 +47,26 @@ pub(super) struct ProjectQuery {
     pub(super) offset: Option<usize>,
 }
 
+impl ProjectQuery {
+    /// Reject an empty `project_root` or an unreasonably large `limit`
+    /// instead of letting them fall through to `unwrap_or_default` and
+    /// behave oddly downstream (e.g. `limit: 0` silently returning nothing).
+    pub(super) fn validate(&self) -> Result<(), ApiError> {
+        if self.project_root.trim().is_empty() {
+            return Err(ApiError::invalid_field("project_root", "must not be empty"));
+        }
+        if let Some(limit) = self.limit {
+            if limit == 0 || limit > MAX_PAGE_LIMIT {
+                return Err(ApiError::invalid_field(
+                    "limit",
+                    format!("must be between 1 and {MAX_PAGE_LIMIT}"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Expand `~` / `~/...` and resolve a project_root string into an absolute,
 /// canonicalized path. Shared by agent / skill / session handlers.
 pub(super) fn canonical_project_root(project_root: &str) -> PathBuf {
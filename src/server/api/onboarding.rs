@@ -0,0 +1,59 @@
+//! `/api/onboarding/*` — first-run setup progress, shared by the desktop
+//! app and CLI so both show consistent guidance and can detect a broken
+//! install. Backed by `onboarding.rs` (`~/.linggen/config/onboarding.json`).
+
+use crate::onboarding::{OnboardingState, OnboardingStep};
+use axum::{response::IntoResponse, Json};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize)]
+pub(crate) struct StepStatus {
+    step: OnboardingStep,
+    done: bool,
+}
+
+#[derive(Serialize)]
+pub(crate) struct OnboardingStateResponse {
+    steps: Vec<StepStatus>,
+    broken_install: bool,
+}
+
+fn state_response(state: &OnboardingState) -> OnboardingStateResponse {
+    OnboardingStateResponse {
+        steps: OnboardingStep::ALL
+            .iter()
+            .map(|&step| StepStatus { step, done: state.is_done(step) })
+            .collect(),
+        broken_install: state.is_broken_install(),
+    }
+}
+
+pub(crate) async fn get_onboarding_state_api() -> impl IntoResponse {
+    let state = OnboardingState::load(&crate::onboarding::onboarding_file());
+    Json(state_response(&state))
+}
+
+#[derive(Deserialize)]
+pub(crate) struct StepRequest {
+    step: OnboardingStep,
+}
+
+pub(crate) async fn advance_onboarding_step_api(Json(body): Json<StepRequest>) -> impl IntoResponse {
+    let file = crate::onboarding::onboarding_file();
+    let mut state = OnboardingState::load(&file);
+    state.advance(body.step);
+    if let Err(e) = state.save(&file) {
+        tracing::warn!("Failed to save onboarding.json: {}", e);
+    }
+    Json(state_response(&state))
+}
+
+pub(crate) async fn skip_onboarding_step_api(Json(body): Json<StepRequest>) -> impl IntoResponse {
+    let file = crate::onboarding::onboarding_file();
+    let mut state = OnboardingState::load(&file);
+    state.skip(body.step);
+    if let Err(e) = state.save(&file) {
+        tracing::warn!("Failed to save onboarding.json: {}", e);
+    }
+    Json(state_response(&state))
+}
@@ -16,6 +16,9 @@ pub(crate) async fn list_sessions(
     State(state): State<Arc<ServerState>>,
     Query(query): Query<ProjectQuery>,
 ) -> impl IntoResponse {
+    if let Err(err) = query.validate() {
+        return err.into_response();
+    }
     match state.manager.global_sessions.list_sessions() {
         Ok(all_sessions) => {
             // Filter by project_root: match sessions whose cwd or project starts with the query path.
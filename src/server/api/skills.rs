@@ -115,6 +115,9 @@ fn normalize_skill_md_path(path: &str) -> Result<String, String> {
 pub(crate) async fn list_skill_files_api(
     Query(query): Query<ProjectQuery>,
 ) -> impl IntoResponse {
+    if let Err(err) = query.validate() {
+        return err.into_response();
+    }
     let root = canonical_project_root(&query.project_root);
     let mut items: Vec<SkillFileListItem> = Vec::new();
 
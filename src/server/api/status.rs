@@ -41,6 +41,13 @@ pub(crate) struct StatusResponse {
     pub session_prompt_tokens: usize,
     /// Accumulated completion tokens this session (in-memory).
     pub session_completion_tokens: usize,
+    /// Whether offline mode is active — see `Config::offline`.
+    pub offline: bool,
+    /// Current context-window size for `session_id`, if provided and known
+    /// (a point-in-time reading from the latest `ContextUsage` event, not an
+    /// accumulator like the token fields above).
+    pub context_tokens: Option<usize>,
+    pub context_token_limit: Option<usize>,
 }
 
 #[derive(Serialize)]
@@ -121,6 +128,19 @@ pub(crate) async fn get_status_api(
         }
     };
 
+    // Only meaningful for a specific session — summing point-in-time context
+    // sizes across sessions wouldn't mean anything, unlike the accumulators above.
+    let (context_tokens, context_token_limit) = match &query.session_id {
+        Some(sid) => {
+            let ctx = state.session_context.lock().await;
+            match ctx.get(sid) {
+                Some((estimated, limit)) => (Some(*estimated), *limit),
+                None => (None, None),
+            }
+        }
+        None => (None, None),
+    };
+
     Json(StatusResponse {
         version: env!("CARGO_PKG_VERSION").to_string(),
         sessions,
@@ -136,6 +156,9 @@ pub(crate) async fn get_status_api(
         models,
         session_prompt_tokens,
         session_completion_tokens,
+        offline: config.offline,
+        context_tokens,
+        context_token_limit,
     })
     .into_response()
 }
@@ -0,0 +1,37 @@
+//! `GET /api/traces` — browse the opt-in LLM call trace log written by
+//! `provider::trace` (prompt, response, latency, token usage per call).
+//! Read-only; the trace file itself is capped and pruned on write, this
+//! just tails it for the settings UI's "recent LLM calls" panel.
+
+use crate::server::error::ApiError;
+use axum::{extract::Query, response::IntoResponse, Json};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub(crate) struct TracesQuery {
+    /// Most recent N rows (default 50, capped at 500).
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+const DEFAULT_LIMIT: usize = 50;
+const MAX_LIMIT: usize = 500;
+
+pub(crate) async fn get_traces_api(Query(query): Query<TracesQuery>) -> impl IntoResponse {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let path = crate::paths::linggen_home().join("traces").join("llm.jsonl");
+    let content = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => {
+            return ApiError::StorageError(format!("failed to read trace log: {e}")).into_response();
+        }
+    };
+    let lines: Vec<&str> = content.lines().collect();
+    let start = lines.len().saturating_sub(limit);
+    let rows: Vec<serde_json::Value> = lines[start..]
+        .iter()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect();
+    Json(serde_json::json!({ "traces": rows, "total": lines.len() })).into_response()
+}
@@ -10,7 +10,7 @@ use ignore::WalkBuilder;
 use serde::{Deserialize, Serialize};
 
 /// Expand `~` to the user's home directory.
-fn expand_project_root(raw: &str) -> PathBuf {
+pub(super) fn expand_project_root(raw: &str) -> PathBuf {
     if raw == "~" {
         dirs::home_dir().unwrap_or_else(|| PathBuf::from("."))
     } else if raw.starts_with("~/") {
@@ -19,6 +19,20 @@ fn expand_project_root(raw: &str) -> PathBuf {
         PathBuf::from(raw)
     }
 }
+
+/// Resolve `rel_path` against `root`, rejecting any escape from it (`..`
+/// in the path, or a symlink that canonicalizes outside `root`).
+pub(super) fn resolve_in_root(root: &std::path::Path, rel_path: &str) -> Option<PathBuf> {
+    if rel_path.contains("..") {
+        return None;
+    }
+    let full = root.join(rel_path);
+    let full = full.canonicalize().unwrap_or(full);
+    if !full.starts_with(root) {
+        return None;
+    }
+    Some(full)
+}
 use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -39,14 +53,10 @@ pub(crate) async fn list_files(
         Err(_) => return StatusCode::NOT_FOUND.into_response(),
     };
     let rel_path = query.path.unwrap_or_default();
-    if rel_path.contains("..") {
-        return StatusCode::BAD_REQUEST.into_response();
-    }
-    let full_path = canonical_root.join(&rel_path);
-    let full_path = full_path.canonicalize().unwrap_or(full_path);
-    if !full_path.starts_with(&canonical_root) {
-        return StatusCode::BAD_REQUEST.into_response();
-    }
+    let full_path = match resolve_in_root(&canonical_root, &rel_path) {
+        Some(p) => p,
+        None => return StatusCode::BAD_REQUEST.into_response(),
+    };
 
     if !full_path.exists() {
         return StatusCode::NOT_FOUND.into_response();
@@ -157,19 +167,15 @@ pub(crate) async fn read_file_api(
         Some(p) => p,
         None => return StatusCode::BAD_REQUEST.into_response(),
     };
-    if rel_path.contains("..") {
-        return StatusCode::BAD_REQUEST.into_response();
-    }
     let project_root = expand_project_root(&query.project_root);
     let canonical_root = match project_root.canonicalize() {
         Ok(r) => r,
         Err(_) => return StatusCode::NOT_FOUND.into_response(),
     };
-    let full_path = canonical_root.join(&rel_path);
-    let full_path = full_path.canonicalize().unwrap_or(full_path);
-    if !full_path.starts_with(&canonical_root) {
-        return StatusCode::BAD_REQUEST.into_response();
-    }
+    let full_path = match resolve_in_root(&canonical_root, &rel_path) {
+        Some(p) => p,
+        None => return StatusCode::BAD_REQUEST.into_response(),
+    };
 
     match std::fs::read_to_string(full_path) {
         Ok(content) => Json(serde_json::json!({ "content": content })).into_response(),
@@ -0,0 +1,112 @@
+//! `ApiError` — a stable, machine-readable error shape for `/api/*`
+//! handlers, serialized as [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807)
+//! problem+json.
+//!
+//! Most handlers still return ad-hoc `(StatusCode, String)` tuples, which
+//! give a caller nothing to branch on but a status code and a free-text
+//! message. New handlers should return `Result<_, ApiError>` instead so
+//! clients (the web UI, the CLI, the SDK) get a stable `code` field to
+//! match on; existing handlers are being migrated over incrementally
+//! rather than in one sweep.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+
+/// A single field-level complaint, for `ApiError::Validation` bodies with
+/// more than one thing wrong (e.g. both `limit` and `offset` out of range).
+#[derive(Debug, Serialize)]
+pub(crate) struct FieldError {
+    pub(crate) field: &'static str,
+    pub(crate) message: String,
+}
+
+/// A handler-level failure, mapped to an HTTP status and a stable string
+/// `code` a caller can match on without parsing `message`.
+#[derive(Debug)]
+pub(crate) enum ApiError {
+    NotFound(String),
+    /// Malformed request (empty/out-of-range field, unknown value, ...).
+    /// One or more field-level complaints — a 422, not a generic 400,
+    /// since the request was well-formed JSON that just failed semantic
+    /// checks.
+    Validation(Vec<FieldError>),
+    ModelNotReady(String),
+    StorageError(String),
+    Internal(String),
+}
+
+impl ApiError {
+    /// Convenience constructor for a single-field validation failure.
+    pub(crate) fn invalid_field(field: &'static str, message: impl Into<String>) -> Self {
+        ApiError::Validation(vec![FieldError { field, message: message.into() }])
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::ModelNotReady(_) => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::StorageError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            ApiError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            ApiError::NotFound(_) => "not_found",
+            ApiError::Validation(_) => "validation",
+            ApiError::ModelNotReady(_) => "model_not_ready",
+            ApiError::StorageError(_) => "storage_error",
+            ApiError::Internal(_) => "internal",
+        }
+    }
+}
+
+/// RFC 7807 problem+json body. `type` is omitted (no per-error docs page
+/// to link to yet); `code` is the field callers should actually match on.
+/// `errors` is only populated for `ApiError::Validation`.
+#[derive(Serialize)]
+struct Problem {
+    title: &'static str,
+    status: u16,
+    detail: String,
+    code: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<FieldError>,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        let code = self.code();
+        let (detail, errors) = match self {
+            ApiError::Validation(errors) => {
+                let detail = errors
+                    .iter()
+                    .map(|e| format!("{}: {}", e.field, e.message))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                (detail, errors)
+            }
+            ApiError::NotFound(d) | ApiError::ModelNotReady(d) | ApiError::StorageError(d) | ApiError::Internal(d) => {
+                (d, Vec::new())
+            }
+        };
+        let body = Problem {
+            title: status.canonical_reason().unwrap_or("Error"),
+            status: status.as_u16(),
+            detail,
+            code,
+            errors,
+        };
+        let mut response = (status, Json(body)).into_response();
+        response
+            .headers_mut()
+            .insert("content-type", "application/problem+json".parse().unwrap());
+        response
+    }
+}
@@ -38,6 +38,10 @@ enum Backend {
     Bridge { module: &'static str, op: &'static str },
     Memory { verb: &'static str },
     Agent,
+    /// Delegates to the same agent-run machinery as `Backend::Agent`, but
+    /// builds the prompt itself from a list of changed file paths instead
+    /// of taking one verbatim from the caller.
+    SummarizeChanges,
     /// Composed read: daemon days rollup + engine in-flight/run state.
     DreamStatus,
     /// Trigger the dream mission through `trigger_mission_core` — the
@@ -455,6 +459,23 @@ const TOOLS: &[McpTool] = &[
         }),
         timeout_ms: 0,
     },
+    // --- summarize_changes: PR/commit context via a delegated agent run ------
+    McpTool {
+        name: "summarize_changes",
+        backend: Backend::SummarizeChanges,
+        description: "Summarize what a set of changed files touch — useful for writing a commit message or PR description. Reads `git diff` (falling back to `git diff --cached`) for the given paths in the workspace, then asks a local Linggen agent to summarize the affected areas and the nature of the change. Give it file paths (relative to the workspace root); omit paths to summarize the whole working-tree diff.",
+        schema: || json!({
+            "type": "object",
+            "properties": {
+                "paths": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Changed file paths, relative to the workspace root. Omit to summarize the full working-tree diff."
+                }
+            }
+        }),
+        timeout_ms: 0,
+    },
 ];
 
 fn rpc_result(id: Value, result: Value) -> Value {
@@ -529,7 +550,11 @@ fn tool_content(text: String, is_error: bool) -> Value {
 fn render_data(tool: &McpTool, data: &Value) -> Value {
     let op = match tool.backend {
         Backend::Bridge { op, .. } => op,
-        Backend::Memory { .. } | Backend::Agent | Backend::DreamStatus | Backend::DreamRun => "",
+        Backend::Memory { .. }
+        | Backend::Agent
+        | Backend::SummarizeChanges
+        | Backend::DreamStatus
+        | Backend::DreamRun => "",
     };
     match op {
         "screenshot" => {
@@ -556,6 +581,45 @@ struct McpDeps<'a> {
     bridge: &'a BridgeHub,
     ling_mem_url: &'a str,
     state: Option<&'a Arc<ServerState>>,
+    /// `McpConfig::memory_write_enabled` — gates the mutating memory verbs.
+    memory_write_enabled: bool,
+}
+
+/// Mutating memory verbs gated by `McpConfig::memory_write_enabled` — everything
+/// else (`search`, `get`, `list`, `issues`) stays read-only and always allowed.
+const MEMORY_WRITE_VERBS: &[&str] = &["add", "update", "delete", "issue_resolve"];
+
+/// Longest diff text handed to the delegated agent — a Linux-kernel-sized
+/// diff would blow the summary prompt's context for no benefit.
+const SUMMARIZE_DIFF_MAX_CHARS: usize = 40_000;
+
+/// `git diff` for the workspace, restricted to `paths` when non-empty.
+/// Falls back to `git diff --cached` (nothing in the working tree, but a
+/// staged commit in progress) when the plain diff is empty.
+fn git_diff_for_paths(paths: &[String]) -> anyhow::Result<String> {
+    let root = super::mcp_agent::workspace_root();
+    let run = |extra: &[&str]| -> anyhow::Result<String> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("diff").args(extra).current_dir(&root);
+        if !paths.is_empty() {
+            cmd.arg("--").args(paths);
+        }
+        let out = cmd.output()?;
+        if !out.status.success() {
+            anyhow::bail!("{}", String::from_utf8_lossy(&out.stderr));
+        }
+        Ok(String::from_utf8_lossy(&out.stdout).into_owned())
+    };
+
+    let mut diff = run(&[])?;
+    if diff.trim().is_empty() {
+        diff = run(&["--cached"])?;
+    }
+    if diff.len() > SUMMARIZE_DIFF_MAX_CHARS {
+        diff.truncate(SUMMARIZE_DIFF_MAX_CHARS);
+        diff.push_str("\n... (diff truncated)");
+    }
+    Ok(diff)
 }
 
 async fn call_tool(deps: &McpDeps<'_>, name: &str, args: Value) -> Result<Value, String> {
@@ -585,6 +649,15 @@ async fn call_tool(deps: &McpDeps<'_>, name: &str, args: Value) -> Result<Value,
             Ok(tool_content(text, true))
         }
         Backend::Memory { verb } => {
+            if MEMORY_WRITE_VERBS.contains(&verb) && !deps.memory_write_enabled {
+                return Ok(tool_content(
+                    format!(
+                        "memory writes are disabled for MCP callers (mcp.memory_write_enabled \
+                        is false) — {verb} was not applied"
+                    ),
+                    true,
+                ));
+            }
             let mut args = args;
             if let Some(obj) = args.as_object_mut() {
                 obj.insert("verb".to_string(), json!(verb));
@@ -618,6 +691,42 @@ async fn call_tool(deps: &McpDeps<'_>, name: &str, args: Value) -> Result<Value,
                 Err(msg) => Ok(tool_content(msg, true)),
             }
         }
+        Backend::SummarizeChanges => {
+            let Some(state) = deps.state else {
+                return Ok(tool_content(
+                    "summarize_changes is unavailable in this context (no daemon state)"
+                        .to_string(),
+                    true,
+                ));
+            };
+            let paths: Vec<String> = args
+                .get("paths")
+                .and_then(Value::as_array)
+                .map(|a| a.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+                .unwrap_or_default();
+            let diff = match git_diff_for_paths(&paths) {
+                Ok(diff) if diff.trim().is_empty() => {
+                    return Ok(tool_content(
+                        "no changes found — the working tree is clean for the given paths"
+                            .to_string(),
+                        true,
+                    ));
+                }
+                Ok(diff) => diff,
+                Err(e) => return Ok(tool_content(format!("failed to read git diff: {e:#}"), true)),
+            };
+            let prompt = format!(
+                "Summarize the diff below: the affected areas of the codebase, the nature of \
+                 the change (feature, fix, refactor, etc.), and anything a reviewer should pay \
+                 attention to. Structure the summary so it could be pasted into a commit message \
+                 or PR description. Do not re-read the files yourself — the full diff is already \
+                 below.\n\n```diff\n{diff}\n```"
+            );
+            match super::mcp_agent::run(state, None, &prompt).await {
+                Ok(text) => Ok(tool_content(text, false)),
+                Err(msg) => Ok(tool_content(msg, true)),
+            }
+        }
         Backend::DreamStatus => {
             let Some(state) = deps.state else {
                 return Ok(tool_content(
@@ -808,13 +917,23 @@ pub(crate) async fn post_handler(
     if !origin_allowed(&headers) {
         return one_shot((StatusCode::FORBIDDEN, "origin not allowed").into_response());
     }
-    let ling_mem_url = state.manager.get_config_snapshot().await.agent.ling_mem_url;
+    let config = state.manager.get_config_snapshot().await;
     let deps = McpDeps {
         bridge: &state.bridge,
-        ling_mem_url: &ling_mem_url,
+        ling_mem_url: &config.agent.ling_mem_url,
         state: Some(&state),
+        memory_write_enabled: config.mcp.memory_write_enabled,
     };
-    match handle_rpc(&deps, &body).await {
+    let is_tool_call = body.get("method").and_then(Value::as_str) == Some("tools/call");
+    let response = handle_rpc(&deps, &body).await;
+    if is_tool_call && config.mcp.audit_log {
+        let caller_token = headers
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .map(fingerprint_token);
+        record_audit(&state, &config.mcp, &body, response.as_ref(), caller_token.as_deref());
+    }
+    match response {
         Some(response) => one_shot(Json(response).into_response()),
         None => one_shot(StatusCode::ACCEPTED.into_response()),
     }
@@ -825,6 +944,149 @@ pub(crate) async fn get_handler() -> Response {
     one_shot(StatusCode::METHOD_NOT_ALLOWED.into_response())
 }
 
+/// One recorded `tools/call` — what left the machine and how much of it,
+/// without the daemon becoming a second copy of the caller's transcript.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct McpAuditEntry {
+    pub at: u64,
+    pub tool: String,
+    /// Argument values, or (when `McpConfig::redact_args`) just their key
+    /// names — queries and code snippets can carry sensitive content, and
+    /// redaction is the default posture.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<Value>,
+    pub byte_count: usize,
+    pub is_error: bool,
+    /// First 12 hex chars of the SHA-256 of the caller's `Authorization`
+    /// header, never the raw token — this server is localhost-only and
+    /// doesn't require one, so most entries have `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caller_token: Option<String>,
+    /// Resource identifiers the call touched (whichever of `url`, `path`,
+    /// `session_id`, `id` were present in the arguments) — best-effort,
+    /// not every tool has an identifiable source.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub sources: Vec<String>,
+}
+
+/// Param keys treated as identifying "what got touched" for the `sources`
+/// audit field. Not exhaustive — just the common shapes across this
+/// server's tool set (browser nav, memory verbs, session-scoped calls).
+const SOURCE_KEYS: &[&str] = &["url", "path", "session_id", "id"];
+
+fn extract_sources(args: &Value) -> Vec<String> {
+    let Some(obj) = args.as_object() else {
+        return Vec::new();
+    };
+    SOURCE_KEYS
+        .iter()
+        .filter_map(|key| obj.get(*key).and_then(Value::as_str).map(String::from))
+        .collect()
+}
+
+/// Fingerprints a caller-supplied token for the audit log — a stable,
+/// non-reversible identifier without persisting the token itself.
+fn fingerprint_token(token: &str) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    format!("{:x}", hasher.finalize())[..12].to_string()
+}
+
+/// Pure redaction step, split out from `record_audit` so it's testable
+/// without a `ServerState`: object args collapse to their key names,
+/// everything else (already `None`, or a non-object shape) passes through.
+fn redact_args(redact: bool, args: Option<Value>) -> Option<Value> {
+    if !redact {
+        return args;
+    }
+    args.as_ref()
+        .and_then(Value::as_object)
+        .map(|obj| json!(obj.keys().cloned().collect::<Vec<_>>()))
+}
+
+/// Append one audit entry for a `tools/call` exchange, trimming the ring
+/// buffer to `cfg.audit_capacity`. A malformed/notification request (no
+/// `response`) still gets recorded with a zero byte count — visibility
+/// into "a call was attempted" matters as much as its result.
+fn record_audit(
+    state: &ServerState,
+    cfg: &crate::config::McpConfig,
+    request: &Value,
+    response: Option<&Value>,
+    caller_token: Option<&str>,
+) {
+    let params = request.get("params").cloned().unwrap_or_default();
+    let tool = params
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or("?")
+        .to_string();
+    let args = params.get("arguments").cloned().filter(|v| !v.is_null());
+    let sources = args.as_ref().map(extract_sources).unwrap_or_default();
+    let args = redact_args(cfg.redact_args, args);
+
+    let result = response.and_then(|r| r.get("result"));
+    // A rejected call (unknown tool, bad params) comes back as a top-level
+    // JSON-RPC `error` member instead of `result` — without checking it, a
+    // rejected call and a real empty success both log `is_error: false`.
+    let is_error = result
+        .and_then(|r| r.get("isError"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+        || response.is_some_and(|r| r.get("error").is_some());
+    let byte_count = result.map(|r| r.to_string().len()).unwrap_or(0);
+
+    let entry = McpAuditEntry {
+        at: crate::util::now_ts_secs(),
+        tool,
+        args,
+        byte_count,
+        is_error,
+        caller_token: caller_token.map(String::from),
+        sources,
+    };
+    let mut log = state.mcp_audit.lock().unwrap();
+    log.push_back(entry);
+    while log.len() > cfg.audit_capacity.max(1) {
+        log.pop_front();
+    }
+}
+
+/// `GET /api/mcp/audit` — most-recent-first view of the ring buffer, for an
+/// admin UI or `linggen doctor`-style visibility into what MCP callers asked
+/// for. Empty (not an error) when the buffer is empty or logging is off.
+pub(crate) async fn audit_log_api(State(state): State<Arc<ServerState>>) -> Response {
+    let entries: Vec<McpAuditEntry> = state
+        .mcp_audit
+        .lock()
+        .unwrap()
+        .iter()
+        .rev()
+        .cloned()
+        .collect();
+    Json(json!({ "entries": entries })).into_response()
+}
+
+/// `GET /api/mcp/audit/export` — the same ring buffer as `audit_log_api`,
+/// oldest-first, one JSON object per line for `jq`/log-shipping consumption
+/// rather than admin-UI display.
+pub(crate) async fn audit_log_export_api(State(state): State<Arc<ServerState>>) -> Response {
+    let body = state
+        .mcp_audit
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|entry| serde_json::to_string(entry).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n");
+    (
+        [(axum::http::header::CONTENT_TYPE, "application/x-ndjson")],
+        body,
+    )
+        .into_response()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -840,7 +1102,19 @@ mod tests {
     // No ServerState in unit tests — agent_run errors cleanly, everything
     // else is state-independent.
     fn deps(hub: &BridgeHub) -> McpDeps<'_> {
-        McpDeps { bridge: hub, ling_mem_url: TEST_MEM_URL, state: None }
+        McpDeps { bridge: hub, ling_mem_url: TEST_MEM_URL, state: None, memory_write_enabled: true }
+    }
+
+    #[test]
+    fn redact_args_collapses_object_to_keys() {
+        let args = json!({ "query": "how does auth work", "limit": 5 });
+        let redacted = redact_args(true, Some(args.clone())).unwrap();
+        let keys = redacted.as_array().unwrap();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains(&json!("query")));
+        assert!(keys.contains(&json!("limit")));
+        assert_eq!(redact_args(false, Some(args)).unwrap()["query"], "how does auth work");
+        assert!(redact_args(true, None).is_none());
     }
 
     #[tokio::test]
@@ -857,11 +1131,12 @@ mod tests {
         let msg = json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" });
         let res = handle_rpc(&deps(&hub()), &msg).await.unwrap();
         let tools = res["result"]["tools"].as_array().unwrap();
-        assert_eq!(tools.len(), 26);
+        assert_eq!(tools.len(), 27);
         assert!(tools.iter().any(|t| t["name"] == "browser_navigate"));
         assert!(tools.iter().any(|t| t["name"] == "x_search"));
         assert!(tools.iter().any(|t| t["name"] == "memory_search"));
         assert!(tools.iter().any(|t| t["name"] == "agent_run"));
+        assert!(tools.iter().any(|t| t["name"] == "summarize_changes"));
         assert!(tools.iter().any(|t| t["name"] == "memory_dream_status"));
         assert!(tools.iter().any(|t| t["name"] == "memory_dream_run"));
         assert!(tools.iter().any(|t| t["name"] == "memory_issues"));
@@ -903,6 +1178,25 @@ mod tests {
         assert_eq!(res["error"]["code"], -32602);
     }
 
+    #[tokio::test]
+    async fn memory_write_disabled_short_circuits_before_the_daemon_call() {
+        let hub = hub();
+        let deps = McpDeps {
+            bridge: &hub,
+            ling_mem_url: TEST_MEM_URL,
+            state: None,
+            memory_write_enabled: false,
+        };
+        let msg = json!({
+            "jsonrpc": "2.0", "id": 6, "method": "tools/call",
+            "params": { "name": "memory_add", "arguments": { "content": "note" } }
+        });
+        let res = handle_rpc(&deps, &msg).await.unwrap();
+        assert_eq!(res["result"]["isError"], true);
+        let text = res["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("memory_write_enabled"));
+    }
+
     #[tokio::test]
     async fn call_without_bridge_is_tool_error_not_rpc_error() {
         let msg = json!({
@@ -48,7 +48,7 @@ fn title_from_prompt(prompt: &str) -> String {
 
 /// The workspace root a delegated run operates in — the daemon's launch dir,
 /// same convention `list_agents_api` uses as its fallback.
-fn workspace_root() -> PathBuf {
+pub(super) fn workspace_root() -> PathBuf {
     let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
     crate::util::resolve_path(&cwd)
 }
@@ -1,6 +1,7 @@
 mod api;
 pub(crate) mod bridge;
 mod chat;
+pub(crate) mod error;
 mod mcp;
 mod mcp_agent;
 mod events;
@@ -860,6 +861,8 @@ async fn prepare_server(
         queue_seq: AtomicU64::new(1),
         event_seq: AtomicU64::new(1),
         session_tokens: Arc::new(Mutex::new(HashMap::new())),
+        session_context: Arc::new(Mutex::new(HashMap::new())),
+        mcp_audit: Arc::new(std::sync::Mutex::new(std::collections::VecDeque::new())),
         whip_token: uuid::Uuid::new_v4().to_string(),
         user_bash_cwd: Arc::new(Mutex::new(HashMap::new())),
         proxy_connections: Arc::new(rtc::proxy_room::ProxyRoomConnections::new()),
@@ -1018,6 +1021,17 @@ async fn prepare_server(
                             entry.0 += prompt;
                             entry.1 += completion;
                         }
+                        // Snapshot the current context-window size (not additive —
+                        // each event replaces the previous reading for the session).
+                        if let crate::engine::agent::AgentEvent::ContextUsage {
+                            estimated_tokens,
+                            token_limit,
+                            ..
+                        } = &other {
+                            let sid = session_id.clone().unwrap_or_else(|| "current".to_string());
+                            let mut ctx = state_clone.session_context.lock().await;
+                            ctx.insert(sid, (*estimated_tokens, *token_limit));
+                        }
                         if let Some(se) = ServerEvent::from_agent_event(other, session_id) {
                             let _ = state_clone.events_tx.send(se);
                         }
@@ -1112,11 +1126,22 @@ async fn prepare_server(
         .route("/api/bridge/call", post(bridge::call_handler))
         .route("/api/bridge/status", get(bridge::status_handler))
         .route("/mcp", post(mcp::post_handler).get(mcp::get_handler))
+        .route("/api/mcp/audit", get(mcp::audit_log_api))
+        .route("/api/mcp/audit/export", get(mcp::audit_log_export_api))
         .route("/api/yinyue/chat", post(api::yinyue::chat_handler))
         .route("/api/presence", post(api::yinyue::presence_handler))
         .route("/api/rtc/whip", post(rtc::whip_handler))
         .route("/api/rtc/token", get(rtc::whip_token_handler))
         .route("/api/status", get(get_status_api))
+        .route("/api/activity", get(api::activity::get_activity_api))
+        .route("/api/context-pack", post(api::context_pack::post_context_pack))
+        .route("/api/events", get(api::events_sse::get_events_api))
+        .route("/api/traces", get(api::traces::get_traces_api))
+        .route("/api/logs", get(api::logs::get_logs_api))
+        .route("/api/logs/level", post(api::logs::post_logs_level))
+        .route("/api/onboarding/state", get(api::onboarding::get_onboarding_state_api))
+        .route("/api/onboarding/advance", post(api::onboarding::advance_onboarding_step_api))
+        .route("/api/onboarding/skip", post(api::onboarding::skip_onboarding_step_api))
         .route("/api/account", get(get_account))
         .route("/api/account/login", post(post_account_login))
         .route("/api/account/callback", get(get_account_callback))
@@ -47,6 +47,15 @@ pub struct ServerState {
     /// Accumulated token usage per session (in-memory, resets on restart).
     /// Key: "{project_root}:{session_id}", Value: (prompt_tokens, completion_tokens).
     pub session_tokens: Arc<Mutex<HashMap<String, (usize, usize)>>>,
+    /// Most recent context-window snapshot per session (in-memory, resets on
+    /// restart), from the latest `AgentEvent::ContextUsage`. Value:
+    /// (estimated_tokens, token_limit) — a point-in-time size, not additive
+    /// like `session_tokens`.
+    pub session_context: Arc<Mutex<HashMap<String, (usize, Option<usize>)>>>,
+    /// Ring buffer of recent `/mcp` `tools/call` invocations — see
+    /// `server::mcp::record_audit`. Plain `std::sync::Mutex`: every access
+    /// is a quick push/trim/clone, never held across an `.await`.
+    pub mcp_audit: Arc<std::sync::Mutex<std::collections::VecDeque<super::mcp::McpAuditEntry>>>,
     /// Random token required for WHIP endpoint authentication.
     /// Generated at startup, passed to the UI via /api/status.
     pub whip_token: String,